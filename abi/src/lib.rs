@@ -0,0 +1,109 @@
+// ABI of the hackathon contract. The snapshot next to this crate is the build artifact frontends
+// generate their types from, the tests keep it in sync with the contract. `versions.txt` holds the
+// hash of the snapshot of every released version, so a version can not be regenerated with a
+// different ABI.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use near_abi::AbiRoot;
+use sha2::{Digest, Sha256};
+
+pub use hack_near::abi::contract_abi;
+
+pub const SNAPSHOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/hack_near_abi.json");
+pub const VERSIONS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/versions.txt");
+
+// Define the version table - the ABI hash of every released version
+pub type Versions = BTreeMap<String, String>;
+
+// Define how an ABI differs from its snapshot
+#[derive(Debug, Clone, PartialEq)]
+pub enum Drift {
+    // The methods or types changed but the version did not
+    Unversioned { version: String },
+    // The version was bumped and the snapshot was not regenerated
+    Stale { snapshot: String, current: String },
+    // The version has no entry in the version table
+    Unrecorded { version: String },
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Drift::Unversioned { version } => write!(
+                f,
+                "the ABI changed but the contract version is still {}, bump it in Cargo.toml \
+                and run `cargo run -p hack-near-abi`",
+                version
+            ),
+            Drift::Stale { snapshot, current } => write!(
+                f,
+                "the snapshot is for version {} but the contract is {}, \
+                run `cargo run -p hack-near-abi`",
+                snapshot, current
+            ),
+            Drift::Unrecorded { version } => write!(
+                f,
+                "version {} has no ABI hash, run `cargo run -p hack-near-abi`",
+                version
+            ),
+        }
+    }
+}
+
+// Compare the ABI of the contract with the hash recorded for its version, then with a snapshot
+pub fn check(versions: &Versions, snapshot: &AbiRoot, current: &AbiRoot) -> Result<(), Drift> {
+    let version = |abi: &AbiRoot| abi.metadata.version.clone().unwrap_or_default();
+    match versions.get(&version(current)) {
+        Some(hash) if *hash != abi_hash(current) => {
+            return Err(Drift::Unversioned {
+                version: version(current),
+            })
+        }
+        Some(_) => {}
+        None => {
+            return Err(Drift::Unrecorded {
+                version: version(current),
+            })
+        }
+    }
+    if snapshot == current {
+        return Ok(());
+    }
+
+    if version(snapshot) == version(current) {
+        Err(Drift::Unversioned {
+            version: version(current),
+        })
+    } else {
+        Err(Drift::Stale {
+            snapshot: version(snapshot),
+            current: version(current),
+        })
+    }
+}
+
+// Hex SHA-256 of the snapshot file of an ABI
+pub fn abi_hash(abi: &AbiRoot) -> String {
+    hex::encode(Sha256::digest(to_json(abi).as_bytes()))
+}
+
+// Read the version table, `#` starts a comment line
+pub fn parse_versions(text: &str) -> Versions {
+    text.lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (version, hash) = line
+                .split_once(' ')
+                .unwrap_or_else(|| panic!("bad version line `{}`", line));
+            (version.to_string(), hash.trim().to_string())
+        })
+        .collect()
+}
+
+pub fn to_json(abi: &AbiRoot) -> String {
+    let mut json = serde_json::to_string_pretty(abi).expect("ABI is always serializable");
+    json.push('\n');
+    json
+}
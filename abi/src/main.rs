@@ -0,0 +1,71 @@
+use std::env;
+use std::fs;
+use std::process;
+
+use std::fs::OpenOptions;
+use std::io::Write;
+
+use hack_near_abi::{abi_hash, contract_abi, parse_versions, to_json, SNAPSHOT, VERSIONS};
+
+const USAGE: &str = "usage: hack-near-abi [FILE]
+
+Write the ABI of the contract to FILE, or stdout with -. Without FILE the snapshot checked by the
+tests is regenerated and the hash of a new version is added to the version table.";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let abi = contract_abi();
+    let json = to_json(&abi);
+
+    let path = match args.as_slice() {
+        [] => {
+            record_version(&abi);
+            SNAPSHOT
+        }
+        [file] if file == "-" => {
+            print!("{}", json);
+            return;
+        }
+        [file] if !file.starts_with('-') => file,
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(err) = fs::write(path, json) {
+        eprintln!("error: {}: {}", path, err);
+        process::exit(1);
+    }
+    println!("wrote {}", path);
+}
+
+// Add the hash of the contract version to the version table, a recorded version must not change
+fn record_version(abi: &near_abi::AbiRoot) {
+    let version = abi.metadata.version.clone().unwrap_or_default();
+    let hash = abi_hash(abi);
+    let versions = parse_versions(&fs::read_to_string(VERSIONS).unwrap_or_default());
+
+    match versions.get(&version) {
+        Some(recorded) if *recorded == hash => {}
+        Some(_) => {
+            eprintln!(
+                "error: the ABI of version {} is already recorded and changed, \
+                bump the version in Cargo.toml",
+                version
+            );
+            process::exit(1);
+        }
+        None => {
+            let written = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(VERSIONS)
+                .and_then(|mut file| writeln!(file, "{} {}", version, hash));
+            if let Err(err) = written {
+                eprintln!("error: {}: {}", VERSIONS, err);
+                process::exit(1);
+            }
+        }
+    }
+}
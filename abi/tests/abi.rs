@@ -0,0 +1,106 @@
+use std::collections::BTreeSet;
+use std::fs;
+
+use hack_near_abi::{
+    abi_hash, check, contract_abi, parse_versions, Drift, Versions, SNAPSHOT, VERSIONS,
+};
+use near_abi::AbiRoot;
+
+const CONTRACT_SOURCE: &str = include_str!("../../src/lib.rs");
+
+fn snapshot() -> AbiRoot {
+    serde_json::from_str(&fs::read_to_string(SNAPSHOT).unwrap()).unwrap()
+}
+
+fn versions() -> Versions {
+    parse_versions(&fs::read_to_string(VERSIONS).unwrap())
+}
+
+#[test]
+fn snapshot_matches_the_contract() {
+    if let Err(drift) = check(&versions(), &snapshot(), &contract_abi()) {
+        panic!("{}", drift);
+    }
+}
+
+#[test]
+fn versions_hash_the_snapshot_file() {
+    let snapshot = snapshot();
+    let version = snapshot.metadata.version.clone().unwrap();
+    assert_eq!(versions()[&version], abi_hash(&snapshot));
+    assert_eq!(
+        fs::read_to_string(SNAPSHOT).unwrap(),
+        hack_near_abi::to_json(&snapshot)
+    );
+}
+
+#[test]
+fn abi_lists_every_public_method() {
+    // Every public method of the contract is a method of a `near_bindgen` impl block
+    let methods: BTreeSet<&str> = CONTRACT_SOURCE
+        .lines()
+        .filter_map(|line| line.strip_prefix("    pub fn "))
+        .map(|line| line.split('(').next().unwrap())
+        .collect();
+    let abi = contract_abi();
+    let functions: BTreeSet<&str> = abi
+        .body
+        .functions
+        .iter()
+        .map(|function| function.name.as_str())
+        .collect();
+    assert_eq!(functions, methods);
+}
+
+#[test]
+fn changes_need_a_version_bump() {
+    let snapshot = snapshot();
+    let version = snapshot.metadata.version.clone().unwrap();
+    let mut current = snapshot.clone();
+    current
+        .body
+        .functions
+        .retain(|function| function.name != "get_team");
+
+    let mut versions = versions();
+    assert_eq!(
+        check(&versions, &snapshot, &current),
+        Err(Drift::Unversioned {
+            version: version.clone()
+        })
+    );
+    // A regenerated snapshot does not hide the change of a recorded version
+    assert_eq!(
+        check(&versions, &current, &current),
+        Err(Drift::Unversioned {
+            version: version.clone()
+        })
+    );
+
+    current.metadata.version = Some(format!("{}-next", version));
+    assert_eq!(
+        check(&versions, &snapshot, &current),
+        Err(Drift::Unrecorded {
+            version: format!("{}-next", version)
+        })
+    );
+    versions.insert(format!("{}-next", version), abi_hash(&current));
+    assert_eq!(
+        check(&versions, &snapshot, &current),
+        Err(Drift::Stale {
+            snapshot: version.clone(),
+            current: format!("{}-next", version)
+        })
+    );
+    assert_eq!(check(&versions, &current, &current), Ok(()));
+}
+
+#[test]
+fn earlier_versions_stay_recorded() {
+    let versions = versions();
+    for version in [
+        "0.1.0", "0.2.0", "0.2.1", "0.2.2", "0.2.3", "0.2.4", "0.2.5",
+    ] {
+        assert_eq!(versions[version].len(), 64, "{}", version);
+    }
+}
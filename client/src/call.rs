@@ -0,0 +1,74 @@
+use std::marker::PhantomData;
+
+use near_sdk::{AccountId, Balance, Gas};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+// Default gas of the calls that only write the entities they name
+pub const CALL_GAS: Gas = Gas(30 * Gas::ONE_TERA.0);
+// Calls that send NEAR back, `award_winner` and the storage functions
+pub const TRANSFER_GAS: Gas = Gas(50 * Gas::ONE_TERA.0);
+// Calls that write a revision, walk every award of a hackathon (`cancel_hackathon`) or call the
+// payout DAO
+pub const HEAVY_GAS: Gas = Gas(100 * Gas::ONE_TERA.0);
+
+// Define the function call structure - everything a transaction action needs to call the contract
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionCall {
+    pub receiver_id: AccountId,
+    pub method_name: &'static str,
+    // JSON encoded arguments
+    pub args: Vec<u8>,
+    pub gas: Gas,
+    pub deposit: Balance,
+}
+
+// Implement the function call structure
+impl FunctionCall {
+    pub(crate) fn new(receiver_id: &AccountId, method_name: &'static str, args: Value) -> Self {
+        FunctionCall {
+            receiver_id: receiver_id.clone(),
+            method_name,
+            args: args.to_string().into_bytes(),
+            gas: CALL_GAS,
+            deposit: 0,
+        }
+    }
+
+    pub fn with_gas(mut self, gas: Gas) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    pub fn with_deposit(mut self, deposit: Balance) -> Self {
+        self.deposit = deposit;
+        self
+    }
+}
+
+// Define the view call structure - a read-only call that decodes its result as `T`
+#[derive(Debug)]
+pub struct ViewCall<T> {
+    pub receiver_id: AccountId,
+    pub method_name: &'static str,
+    // JSON encoded arguments
+    pub args: Vec<u8>,
+    result: PhantomData<fn() -> T>,
+}
+
+// Implement the view call structure
+impl<T: DeserializeOwned> ViewCall<T> {
+    pub(crate) fn new(receiver_id: &AccountId, method_name: &'static str, args: Value) -> Self {
+        ViewCall {
+            receiver_id: receiver_id.clone(),
+            method_name,
+            args: args.to_string().into_bytes(),
+            result: PhantomData,
+        }
+    }
+
+    // Decode the raw result of the view, as returned by the `call_function` RPC query
+    pub fn decode(&self, result: &[u8]) -> Result<T, serde_json::Error> {
+        serde_json::from_slice(result)
+    }
+}
@@ -0,0 +1,772 @@
+use hack_near::types::*;
+use near_sdk::json_types::{Base58CryptoHash, U128, U64};
+use near_sdk::{AccountId, Balance, Timestamp, ONE_YOCTO};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::call::{FunctionCall, ViewCall, HEAVY_GAS, TRANSFER_GAS};
+
+// Define the project submission structure - the arguments of `submit_project`
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ProjectSubmission {
+    pub hackathon_id: HackathonId,
+    pub team_id: TeamId,
+    pub categories: Vec<CategoryId>,
+    pub name: String,
+    pub description: String,
+    pub image: String,
+    // Deprecated, prefer `artifacts`
+    pub link: Vec<String>,
+    pub time: Timestamp,
+    pub artifacts: Option<Vec<Artifact>>,
+}
+
+// Define the HackathonClient structure - builds the calls of one deployed contract
+#[derive(Debug, Clone)]
+pub struct HackathonClient {
+    pub contract_id: AccountId,
+}
+
+// Implement the call builders. Storage is paid from the `storage_deposit` balance, so only the
+// payable functions recommend a deposit
+impl HackathonClient {
+    pub fn new(contract_id: AccountId) -> Self {
+        HackathonClient { contract_id }
+    }
+
+    fn call(&self, method_name: &'static str, args: serde_json::Value) -> FunctionCall {
+        FunctionCall::new(&self.contract_id, method_name, args)
+    }
+
+    pub fn add_member(&self, name: &str, image: Option<&str>, bio: Option<&str>) -> FunctionCall {
+        self.call(
+            "add_member",
+            json!({ "name": name, "image": image, "bio": bio }),
+        )
+    }
+
+    pub fn update_member(
+        &self,
+        name: Option<&str>,
+        image: Option<&str>,
+        bio: Option<&str>,
+    ) -> FunctionCall {
+        self.call(
+            "update_member",
+            json!({ "name": name, "image": image, "bio": bio }),
+        )
+    }
+
+    pub fn add_hackathon(&self, payload: &HackathonPayload) -> FunctionCall {
+        self.call("add_hackathon", json!({ "payload": payload }))
+    }
+
+    pub fn update_hackathon(
+        &self,
+        hackathon_id: HackathonId,
+        payload: &HackathonUpdatePayload,
+    ) -> FunctionCall {
+        self.call(
+            "update_hackathon",
+            json!({ "hackathon_id": hackathon_id, "payload": payload }),
+        )
+    }
+
+    pub fn cancel_hackathon(&self, hackathon_id: HackathonId) -> FunctionCall {
+        self.call("cancel_hackathon", json!({ "hackathon_id": hackathon_id }))
+            .with_gas(HEAVY_GAS)
+    }
+
+    pub fn add_category(&self, hackathon_id: HackathonId, name: &str) -> FunctionCall {
+        self.call(
+            "add_category",
+            json!({ "hackathon_id": hackathon_id, "name": name }),
+        )
+    }
+
+    pub fn update_category(
+        &self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        name: &str,
+    ) -> FunctionCall {
+        self.call(
+            "update_category",
+            json!({ "hackathon_id": hackathon_id, "category_id": category_id, "name": name }),
+        )
+    }
+
+    // `total` is in NEAR
+    pub fn add_award(
+        &self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        name: &str,
+        total: f64,
+    ) -> FunctionCall {
+        self.call(
+            "add_award",
+            json!({
+                "hackathon_id": hackathon_id,
+                "category_id": category_id,
+                "name": name,
+                "total": total,
+            }),
+        )
+    }
+
+    pub fn update_award(
+        &self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        name: Option<&str>,
+        total: Option<f64>,
+    ) -> FunctionCall {
+        self.call(
+            "update_award",
+            json!({
+                "hackathon_id": hackathon_id,
+                "category_id": category_id,
+                "award_id": award_id,
+                "name": name,
+                "total": total,
+            }),
+        )
+    }
+
+    pub fn add_organizer(&self, hackathon_id: HackathonId, account_id: &AccountId) -> FunctionCall {
+        self.call(
+            "add_organizer",
+            json!({ "hackathon_id": hackathon_id, "account_id": account_id }),
+        )
+    }
+
+    pub fn remove_organizer(
+        &self,
+        hackathon_id: HackathonId,
+        account_id: &AccountId,
+    ) -> FunctionCall {
+        self.call(
+            "remove_organizer",
+            json!({ "hackathon_id": hackathon_id, "account_id": account_id }),
+        )
+    }
+
+    pub fn propose_owner(
+        &self,
+        hackathon_id: HackathonId,
+        new_owner: Option<&AccountId>,
+    ) -> FunctionCall {
+        self.call(
+            "propose_owner",
+            json!({ "hackathon_id": hackathon_id, "new_owner": new_owner }),
+        )
+    }
+
+    pub fn accept_owner(&self, hackathon_id: HackathonId) -> FunctionCall {
+        self.call("accept_owner", json!({ "hackathon_id": hackathon_id }))
+    }
+
+    pub fn set_payout_dao(
+        &self,
+        hackathon_id: HackathonId,
+        dao_id: Option<&AccountId>,
+    ) -> FunctionCall {
+        self.call(
+            "set_payout_dao",
+            json!({ "hackathon_id": hackathon_id, "dao_id": dao_id }),
+        )
+    }
+
+    pub fn join_hackathon(&self, hackathon_id: HackathonId) -> FunctionCall {
+        self.call("join_hackathon", json!({ "hackathon_id": hackathon_id }))
+    }
+
+    pub fn leave_hackathon(&self, hackathon_id: HackathonId) -> FunctionCall {
+        self.call("leave_hackathon", json!({ "hackathon_id": hackathon_id }))
+            .with_gas(HEAVY_GAS)
+    }
+
+    pub fn create_team(&self, hackathon_id: HackathonId, name: &str) -> FunctionCall {
+        self.call(
+            "create_team",
+            json!({ "hackathon_id": hackathon_id, "name": name }),
+        )
+    }
+
+    pub fn invite_to_team(&self, team_id: TeamId, account_id: &AccountId) -> FunctionCall {
+        self.call(
+            "invite_to_team",
+            json!({ "team_id": team_id, "account_id": account_id }),
+        )
+    }
+
+    pub fn accept_team_invitation(&self, team_id: TeamId) -> FunctionCall {
+        self.call("accept_team_invitation", json!({ "team_id": team_id }))
+    }
+
+    pub fn decline_team_invitation(&self, team_id: TeamId) -> FunctionCall {
+        self.call("decline_team_invitation", json!({ "team_id": team_id }))
+    }
+
+    pub fn leave_team(&self, team_id: TeamId) -> FunctionCall {
+        self.call("leave_team", json!({ "team_id": team_id }))
+    }
+
+    pub fn post_looking_for_team(
+        &self,
+        hackathon_id: HackathonId,
+        skills: &[String],
+        message: &str,
+    ) -> FunctionCall {
+        self.call(
+            "post_looking_for_team",
+            json!({ "hackathon_id": hackathon_id, "skills": skills, "message": message }),
+        )
+    }
+
+    pub fn post_looking_for_members(
+        &self,
+        team_id: TeamId,
+        skills: &[String],
+        message: &str,
+    ) -> FunctionCall {
+        self.call(
+            "post_looking_for_members",
+            json!({ "team_id": team_id, "skills": skills, "message": message }),
+        )
+    }
+
+    pub fn remove_post(&self, hackathon_id: HackathonId, team_id: Option<TeamId>) -> FunctionCall {
+        self.call(
+            "remove_post",
+            json!({ "hackathon_id": hackathon_id, "team_id": team_id }),
+        )
+    }
+
+    pub fn prune_posts(&self, hackathon_id: HackathonId) -> FunctionCall {
+        self.call("prune_posts", json!({ "hackathon_id": hackathon_id }))
+    }
+
+    pub fn submit_project(&self, submission: &ProjectSubmission) -> FunctionCall {
+        self.call("submit_project", json!(submission))
+            .with_gas(HEAVY_GAS)
+    }
+
+    pub fn update_submission(
+        &self,
+        submission_id: SubmissionId,
+        payload: &SubmissionUpdatePayload,
+    ) -> FunctionCall {
+        self.call(
+            "update_submission",
+            json!({ "submission_id": submission_id, "payload": payload }),
+        )
+        .with_gas(HEAVY_GAS)
+    }
+
+    pub fn anchor_commit(&self, submission_id: SubmissionId, commit_hash: &str) -> FunctionCall {
+        self.call(
+            "anchor_commit",
+            json!({ "submission_id": submission_id, "commit_hash": commit_hash }),
+        )
+    }
+
+    pub fn withdraw_submission(
+        &self,
+        hackathon_id: HackathonId,
+        submission_id: SubmissionId,
+    ) -> FunctionCall {
+        self.call(
+            "withdraw_submission",
+            json!({ "hackathon_id": hackathon_id, "submission_id": submission_id }),
+        )
+        .with_gas(HEAVY_GAS)
+    }
+
+    pub fn judge_winner(
+        &self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        submission_id: SubmissionId,
+    ) -> FunctionCall {
+        self.call(
+            "judge_winner",
+            json!({
+                "hackathon_id": hackathon_id,
+                "category_id": category_id,
+                "award_id": award_id,
+                "submission_id": submission_id,
+            }),
+        )
+    }
+
+    // Escrow part of an award, `amount` is in yoctoNEAR
+    pub fn fund_award(
+        &self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        amount: Balance,
+    ) -> FunctionCall {
+        self.call(
+            "fund_award",
+            json!({
+                "hackathon_id": hackathon_id,
+                "category_id": category_id,
+                "award_id": award_id,
+            }),
+        )
+        .with_deposit(amount)
+    }
+
+    // Pay an award, `unfunded` must be the part of the price that was not escrowed yet
+    pub fn award_winner(
+        &self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        unfunded: Balance,
+    ) -> FunctionCall {
+        self.call(
+            "award_winner",
+            json!({
+                "hackathon_id": hackathon_id,
+                "category_id": category_id,
+                "award_id": award_id,
+            }),
+        )
+        .with_gas(TRANSFER_GAS)
+        .with_deposit(unfunded)
+    }
+
+    // Raise an award as a transfer proposal of the payout DAO, `bond` is the DAO's proposal bond
+    pub fn propose_award_payout(
+        &self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        bond: Balance,
+    ) -> FunctionCall {
+        self.call(
+            "propose_award_payout",
+            json!({
+                "hackathon_id": hackathon_id,
+                "category_id": category_id,
+                "award_id": award_id,
+            }),
+        )
+        .with_gas(HEAVY_GAS)
+        .with_deposit(bond)
+    }
+
+    pub fn check_award_payout(
+        &self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+    ) -> FunctionCall {
+        self.call(
+            "check_award_payout",
+            json!({
+                "hackathon_id": hackathon_id,
+                "category_id": category_id,
+                "award_id": award_id,
+            }),
+        )
+        .with_gas(HEAVY_GAS)
+    }
+
+    pub fn reset_award_payout(
+        &self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+    ) -> FunctionCall {
+        self.call(
+            "reset_award_payout",
+            json!({
+                "hackathon_id": hackathon_id,
+                "category_id": category_id,
+                "award_id": award_id,
+            }),
+        )
+    }
+
+    pub fn storage_deposit(
+        &self,
+        account_id: Option<&AccountId>,
+        registration_only: Option<bool>,
+        amount: Balance,
+    ) -> FunctionCall {
+        self.call(
+            "storage_deposit",
+            json!({ "account_id": account_id, "registration_only": registration_only }),
+        )
+        .with_gas(TRANSFER_GAS)
+        .with_deposit(amount)
+    }
+
+    pub fn storage_withdraw(&self, amount: Option<Balance>) -> FunctionCall {
+        self.call("storage_withdraw", json!({ "amount": amount.map(U128) }))
+            .with_gas(TRANSFER_GAS)
+            .with_deposit(ONE_YOCTO)
+    }
+
+    pub fn propose_contract_owner(&self, owner_id: Option<&AccountId>) -> FunctionCall {
+        self.call("propose_contract_owner", json!({ "owner_id": owner_id }))
+    }
+
+    pub fn accept_contract_owner(&self) -> FunctionCall {
+        self.call("accept_contract_owner", json!({}))
+    }
+
+    // `upgrade_delay` is in nanoseconds
+    pub fn set_upgrade_delay(&self, upgrade_delay: u64) -> FunctionCall {
+        self.call(
+            "set_upgrade_delay",
+            json!({ "upgrade_delay": U64(upgrade_delay) }),
+        )
+    }
+
+    pub fn stage_upgrade(&self, code_hash: Base58CryptoHash) -> FunctionCall {
+        self.call("stage_upgrade", json!({ "code_hash": code_hash }))
+    }
+
+    pub fn cancel_upgrade(&self) -> FunctionCall {
+        self.call("cancel_upgrade", json!({}))
+    }
+}
+
+// Implement the view builders
+impl HackathonClient {
+    fn view<T: serde::de::DeserializeOwned>(
+        &self,
+        method_name: &'static str,
+        args: serde_json::Value,
+    ) -> ViewCall<T> {
+        ViewCall::new(&self.contract_id, method_name, args)
+    }
+
+    pub fn storage_balance_of(&self, account_id: &AccountId) -> ViewCall<Option<StorageBalance>> {
+        self.view("storage_balance_of", json!({ "account_id": account_id }))
+    }
+
+    pub fn storage_balance_bounds(&self) -> ViewCall<StorageBalanceBounds> {
+        self.view("storage_balance_bounds", json!({}))
+    }
+
+    pub fn get_all_hackathons(
+        &self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<Option<HackathonWithTotalPrize>>> {
+        self.view(
+            "get_all_hackathons",
+            json!({ "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_all_members(
+        &self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<MemberJson>> {
+        self.view(
+            "get_all_members",
+            json!({ "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_hackathon_participants(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<MemberJson>> {
+        self.view(
+            "get_hackathon_participants",
+            json!({ "hackathon_id": hackathon_id, "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_hackathon_submissions(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<SubmissionJson>> {
+        self.view(
+            "get_hackathon_submissions",
+            json!({ "hackathon_id": hackathon_id, "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_member_joined_hackathons(
+        &self,
+        account_id: &AccountId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<HackathonSummary>> {
+        self.view(
+            "get_member_joined_hackathons",
+            json!({ "account_id": account_id, "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_member_created_hackathons(
+        &self,
+        account_id: &AccountId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<HackathonSummary>> {
+        self.view(
+            "get_member_created_hackathons",
+            json!({ "account_id": account_id, "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_hackathons_by_tag(
+        &self,
+        tag: &str,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<HackathonSummary>> {
+        self.view(
+            "get_hackathons_by_tag",
+            json!({ "tag": tag, "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_hackathons_in_range(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+        after: Option<(Timestamp, HackathonId)>,
+        limit: Option<u64>,
+    ) -> ViewCall<CursorPage<HackathonSummary, (Timestamp, HackathonId)>> {
+        self.view(
+            "get_hackathons_in_range",
+            json!({ "from": from, "to": to, "after": after, "limit": limit }),
+        )
+    }
+
+    pub fn get_upcoming_hackathons(
+        &self,
+        sort: Option<HackathonSort>,
+        after: Option<CalendarCursor>,
+        limit: Option<u64>,
+    ) -> ViewCall<CursorPage<HackathonSummary, CalendarCursor>> {
+        self.view(
+            "get_upcoming_hackathons",
+            json!({ "sort": sort, "after": after, "limit": limit }),
+        )
+    }
+
+    pub fn get_ongoing_hackathons(
+        &self,
+        sort: Option<HackathonSort>,
+        after: Option<CalendarCursor>,
+        limit: Option<u64>,
+    ) -> ViewCall<CursorPage<HackathonSummary, CalendarCursor>> {
+        self.view(
+            "get_ongoing_hackathons",
+            json!({ "sort": sort, "after": after, "limit": limit }),
+        )
+    }
+
+    pub fn get_past_hackathons(
+        &self,
+        sort: Option<HackathonSort>,
+        after: Option<CalendarCursor>,
+        limit: Option<u64>,
+    ) -> ViewCall<CursorPage<HackathonSummary, CalendarCursor>> {
+        self.view(
+            "get_past_hackathons",
+            json!({ "sort": sort, "after": after, "limit": limit }),
+        )
+    }
+
+    pub fn get_prize_totals_check(
+        &self,
+        hackathon_id: HackathonId,
+    ) -> ViewCall<Option<PrizeTotalsCheck>> {
+        self.view(
+            "get_prize_totals_check",
+            json!({ "hackathon_id": hackathon_id }),
+        )
+    }
+
+    pub fn get_hackathon_categories(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<CategoryJson>> {
+        self.view(
+            "get_hackathon_categories",
+            json!({ "hackathon_id": hackathon_id, "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_hackathon(
+        &self,
+        hackathon_id: HackathonId,
+    ) -> ViewCall<Option<HackathonWithTotalPrize>> {
+        self.view("get_hackathon", json!({ "hackathon_id": hackathon_id }))
+    }
+
+    pub fn get_hackathon_summary(
+        &self,
+        hackathon_id: HackathonId,
+    ) -> ViewCall<Option<HackathonSummary>> {
+        self.view(
+            "get_hackathon_summary",
+            json!({ "hackathon_id": hackathon_id }),
+        )
+    }
+
+    pub fn get_hackathon_summaries(
+        &self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<HackathonSummary>> {
+        self.view(
+            "get_hackathon_summaries",
+            json!({ "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_detail_hackathon_by_id(
+        &self,
+        hackathon_id: HackathonId,
+    ) -> ViewCall<Option<HackathonJson>> {
+        self.view(
+            "get_detail_hackathon_by_id",
+            json!({ "hackathon_id": hackathon_id }),
+        )
+    }
+
+    pub fn get_hackathon_edits(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<Edit>> {
+        self.view(
+            "get_hackathon_edits",
+            json!({ "hackathon_id": hackathon_id, "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_contract_owner(&self) -> ViewCall<AccountId> {
+        self.view("get_contract_owner", json!({}))
+    }
+
+    pub fn get_upgrade_delay(&self) -> ViewCall<U64> {
+        self.view("get_upgrade_delay", json!({}))
+    }
+
+    pub fn get_staged_upgrade(&self) -> ViewCall<Option<StagedUpgrade>> {
+        self.view("get_staged_upgrade", json!({}))
+    }
+
+    pub fn get_state_version(&self) -> ViewCall<StateVersion> {
+        self.view("get_state_version", json!({}))
+    }
+
+    pub fn contract_source_metadata(&self) -> ViewCall<ContractSourceMetadata> {
+        self.view("contract_source_metadata", json!({}))
+    }
+
+    pub fn get_team(&self, team_id: TeamId) -> ViewCall<Option<Team>> {
+        self.view("get_team", json!({ "team_id": team_id }))
+    }
+
+    pub fn get_hackathon_teams(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<Team>> {
+        self.view(
+            "get_hackathon_teams",
+            json!({ "hackathon_id": hackathon_id, "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_member_invitations(
+        &self,
+        account_id: &AccountId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<Team>> {
+        self.view(
+            "get_member_invitations",
+            json!({ "account_id": account_id, "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_posts(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<Post>> {
+        self.view(
+            "get_posts",
+            json!({ "hackathon_id": hackathon_id, "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_post_matches(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<PostMatch>> {
+        self.view(
+            "get_post_matches",
+            json!({ "hackathon_id": hackathon_id, "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_submission_revision(
+        &self,
+        submission_id: SubmissionId,
+        revision: u32,
+    ) -> ViewCall<Option<SubmissionRevision>> {
+        self.view(
+            "get_submission_revision",
+            json!({ "submission_id": submission_id, "revision": revision }),
+        )
+    }
+
+    pub fn get_submission_revisions(
+        &self,
+        submission_id: SubmissionId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> ViewCall<Page<SubmissionRevision>> {
+        self.view(
+            "get_submission_revisions",
+            json!({ "submission_id": submission_id, "from_index": from_index, "limit": limit }),
+        )
+    }
+
+    pub fn get_deadline_revision(
+        &self,
+        submission_id: SubmissionId,
+    ) -> ViewCall<Option<SubmissionRevision>> {
+        self.view(
+            "get_deadline_revision",
+            json!({ "submission_id": submission_id }),
+        )
+    }
+
+    pub fn get_user_information_by_id(&self, id: &AccountId) -> ViewCall<Option<MemberJsonDetail>> {
+        self.view("get_user_information_by_id", json!({ "id": id }))
+    }
+}
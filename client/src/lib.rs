@@ -0,0 +1,10 @@
+// Typed client for the JSON interface of the hackathon contract. It builds the method name,
+// arguments, gas and deposit of every call, and decodes view results into the contract's own
+// response types, without depending on a particular RPC library.
+
+mod call;
+mod client;
+
+pub use call::{FunctionCall, ViewCall, CALL_GAS, HEAVY_GAS, TRANSFER_GAS};
+pub use client::{HackathonClient, ProjectSubmission};
+pub use hack_near::types;
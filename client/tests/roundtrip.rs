@@ -0,0 +1,629 @@
+use hack_near::HackathonContract;
+use std::collections::{BTreeMap, BTreeSet};
+
+use hack_near_client::types::{
+    CalendarCursor, HackathonPayload, HackathonPhase, HackathonSettings, HackathonUpdatePayload,
+    SubmissionUpdatePayload,
+};
+use hack_near_client::{
+    FunctionCall, HackathonClient, ProjectSubmission, ViewCall, CALL_GAS, HEAVY_GAS, TRANSFER_GAS,
+};
+use near_sdk::json_types::U128;
+use near_sdk::serde::de::DeserializeOwned;
+use near_sdk::test_utils::{accounts, VMContextBuilder};
+use near_sdk::{testing_env, AccountId, Timestamp, ONE_NEAR, ONE_YOCTO};
+use serde_json::{json, Value};
+
+const START: Timestamp = 1_000;
+const BUILDING: Timestamp = 5_000;
+const END: Timestamp = 10_000;
+const ABI: &str = include_str!("../../abi/hack_near_abi.json");
+// Deployment, migration, callbacks and owner repairs are not built by the client
+const NOT_BUILT: [&str; 6] = [
+    "init",
+    "migrate",
+    "on_payout_checked",
+    "on_payout_proposed",
+    "on_upgrade_deployed",
+    "repair_members_list",
+];
+
+fn client() -> HackathonClient {
+    HackathonClient::new("hackathon.near".parse().unwrap())
+}
+
+// Decode one argument with the contract's own type, as the generated entry point does
+fn arg<T: DeserializeOwned>(call: &FunctionCall, name: &str) -> T {
+    let args: Value = serde_json::from_slice(&call.args).unwrap();
+    serde_json::from_value(args[name].clone())
+        .unwrap_or_else(|err| panic!("{}: bad `{}`: {}", call.method_name, name, err))
+}
+
+// Run a call against the contract and return its result as JSON
+fn execute(
+    contract: &mut HackathonContract,
+    signer: AccountId,
+    time: Timestamp,
+    call: &FunctionCall,
+) -> Value {
+    testing_env!(VMContextBuilder::new()
+        .current_account_id(call.receiver_id.clone())
+        .signer_account_id(signer.clone())
+        .predecessor_account_id(signer)
+        .block_timestamp(time)
+        .attached_deposit(call.deposit)
+        .prepaid_gas(call.gas)
+        .build());
+
+    match call.method_name {
+        "storage_deposit" => {
+            json!(contract.storage_deposit(arg(call, "account_id"), arg(call, "registration_only")))
+        }
+        "add_member" => {
+            json!(contract.add_member(arg(call, "name"), arg(call, "image"), arg(call, "bio")))
+        }
+        "add_hackathon" => json!(contract.add_hackathon(arg(call, "payload"))),
+        "add_category" => {
+            json!(contract.add_category(arg(call, "hackathon_id"), arg(call, "name")))
+        }
+        "add_award" => json!(contract.add_award(
+            arg(call, "hackathon_id"),
+            arg(call, "category_id"),
+            arg(call, "name"),
+            arg(call, "total")
+        )),
+        "join_hackathon" => json!(contract.join_hackathon(arg(call, "hackathon_id"))),
+        "create_team" => json!(contract.create_team(arg(call, "hackathon_id"), arg(call, "name"))),
+        "invite_to_team" => {
+            json!(contract.invite_to_team(arg(call, "team_id"), arg(call, "account_id")))
+        }
+        "accept_team_invitation" => json!(contract.accept_team_invitation(arg(call, "team_id"))),
+        "submit_project" => json!(contract.submit_project(
+            arg(call, "hackathon_id"),
+            arg(call, "team_id"),
+            arg(call, "categories"),
+            arg(call, "name"),
+            arg(call, "description"),
+            arg(call, "image"),
+            arg(call, "link"),
+            arg(call, "time"),
+            arg(call, "artifacts")
+        )),
+        "judge_winner" => json!(contract.judge_winner(
+            arg(call, "hackathon_id"),
+            arg(call, "category_id"),
+            arg(call, "award_id"),
+            arg(call, "submission_id")
+        )),
+        "fund_award" => json!(contract.fund_award(
+            arg(call, "hackathon_id"),
+            arg(call, "category_id"),
+            arg(call, "award_id")
+        )),
+        "award_winner" => {
+            contract.award_winner(
+                arg(call, "hackathon_id"),
+                arg(call, "category_id"),
+                arg(call, "award_id"),
+            );
+            Value::Null
+        }
+        "update_hackathon" => {
+            contract.update_hackathon(arg(call, "hackathon_id"), arg(call, "payload"));
+            Value::Null
+        }
+        "update_submission" => {
+            contract.update_submission(arg(call, "submission_id"), arg(call, "payload"));
+            Value::Null
+        }
+        "propose_owner" => {
+            contract.propose_owner(arg(call, "hackathon_id"), arg(call, "new_owner"));
+            Value::Null
+        }
+        "accept_owner" => {
+            contract.accept_owner(arg(call, "hackathon_id"));
+            Value::Null
+        }
+        "update_member" => {
+            json!(contract.update_member(arg(call, "name"), arg(call, "image"), arg(call, "bio")))
+        }
+        method_name => panic!("{} is not covered by the test", method_name),
+    }
+}
+
+// Parameter names of every method in the committed ABI, by method name and kind
+fn abi_params() -> BTreeMap<String, (String, BTreeSet<String>)> {
+    let abi: Value = serde_json::from_str(ABI).unwrap();
+    abi["body"]["functions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|function| {
+            let params = function["params"]["args"]
+                .as_array()
+                .map(|args| {
+                    args.iter()
+                        .map(|arg| arg["name"].as_str().unwrap().to_string())
+                        .collect()
+                })
+                .unwrap_or_default();
+            (
+                function["name"].as_str().unwrap().to_string(),
+                (function["kind"].as_str().unwrap().to_string(), params),
+            )
+        })
+        .collect()
+}
+
+fn view_args<T: DeserializeOwned>(view: ViewCall<T>) -> (&'static str, Vec<u8>) {
+    (view.method_name, view.args)
+}
+
+// Names of the arguments a builder sends, the args must be one JSON object
+fn arg_names(method_name: &str, args: &[u8]) -> BTreeSet<String> {
+    let args: Value = serde_json::from_slice(args).unwrap();
+    args.as_object()
+        .unwrap_or_else(|| panic!("{}: args are not an object", method_name))
+        .keys()
+        .cloned()
+        .collect()
+}
+
+fn payload(name: &str) -> HackathonPayload {
+    HackathonPayload {
+        name: name.to_string(),
+        description: "description".to_string(),
+        tags: vec!["near".to_string()],
+        image: "image".to_string(),
+        start: START,
+        end: END,
+        settings: HackathonSettings {
+            max_team_size: Some(2),
+            ..Default::default()
+        },
+        content: None,
+        payout_dao: None,
+    }
+}
+
+#[test]
+fn calls_decode_with_the_contract_types() {
+    let client = client();
+    testing_env!(VMContextBuilder::new()
+        .current_account_id(client.contract_id.clone())
+        .predecessor_account_id(accounts(0))
+        .build());
+    let mut contract = HackathonContract::init(None, None);
+
+    for i in 0..4 {
+        let deposit = client.storage_deposit(None, None, ONE_NEAR);
+        execute(&mut contract, accounts(i), 0, &deposit);
+        let name = format!("member {}", i);
+        execute(
+            &mut contract,
+            accounts(i),
+            0,
+            &client.add_member(&name, None, Some("bio")),
+        );
+    }
+
+    let alice = accounts(0);
+    let hackathon_id: u64 = serde_json::from_value(execute(
+        &mut contract,
+        alice.clone(),
+        0,
+        &client.add_hackathon(&payload("h")),
+    ))
+    .unwrap();
+    let category_id: u64 = serde_json::from_value(execute(
+        &mut contract,
+        alice.clone(),
+        0,
+        &client.add_category(hackathon_id, "c"),
+    ))
+    .unwrap();
+    let award_id: u64 = serde_json::from_value(execute(
+        &mut contract,
+        alice.clone(),
+        0,
+        &client.add_award(hackathon_id, category_id, "a", 1.5),
+    ))
+    .unwrap();
+
+    for i in 1..3 {
+        execute(
+            &mut contract,
+            accounts(i),
+            0,
+            &client.join_hackathon(hackathon_id),
+        );
+    }
+    let team_id: u64 = serde_json::from_value(execute(
+        &mut contract,
+        accounts(1),
+        BUILDING,
+        &client.create_team(hackathon_id, "t"),
+    ))
+    .unwrap();
+    execute(
+        &mut contract,
+        accounts(1),
+        BUILDING,
+        &client.invite_to_team(team_id, &accounts(2)),
+    );
+    execute(
+        &mut contract,
+        accounts(2),
+        BUILDING,
+        &client.accept_team_invitation(team_id),
+    );
+
+    let submission = ProjectSubmission {
+        hackathon_id,
+        team_id,
+        categories: vec![category_id],
+        name: "project".to_string(),
+        description: "description".to_string(),
+        image: "image".to_string(),
+        link: vec!["https://example.com".to_string()],
+        time: BUILDING,
+        artifacts: None,
+    };
+    execute(
+        &mut contract,
+        accounts(1),
+        BUILDING,
+        &client.submit_project(&submission),
+    );
+    let update = SubmissionUpdatePayload {
+        description: Some("updated".to_string()),
+        ..Default::default()
+    };
+    execute(
+        &mut contract,
+        accounts(2),
+        BUILDING,
+        &client.update_submission(0, &update),
+    );
+    let update = HackathonUpdatePayload {
+        description: Some("updated".to_string()),
+        ..Default::default()
+    };
+    execute(
+        &mut contract,
+        alice.clone(),
+        BUILDING,
+        &client.update_hackathon(hackathon_id, &update),
+    );
+    execute(
+        &mut contract,
+        alice.clone(),
+        BUILDING,
+        &client.propose_owner(hackathon_id, Some(&accounts(3))),
+    );
+    execute(
+        &mut contract,
+        accounts(3),
+        BUILDING,
+        &client.accept_owner(hackathon_id),
+    );
+    execute(
+        &mut contract,
+        accounts(3),
+        BUILDING,
+        &client.propose_owner(hackathon_id, Some(&alice)),
+    );
+    execute(
+        &mut contract,
+        alice.clone(),
+        BUILDING,
+        &client.accept_owner(hackathon_id),
+    );
+    execute(
+        &mut contract,
+        alice.clone(),
+        END,
+        &client.judge_winner(hackathon_id, category_id, award_id, 0),
+    );
+    execute(
+        &mut contract,
+        alice.clone(),
+        END,
+        &client.fund_award(hackathon_id, category_id, award_id, ONE_NEAR),
+    );
+    execute(
+        &mut contract,
+        alice,
+        END,
+        &client.award_winner(hackathon_id, category_id, award_id, ONE_NEAR / 2),
+    );
+
+    let detail = contract.get_detail_hackathon_by_id(hackathon_id).unwrap();
+    assert_eq!(
+        contract
+            .get_hackathon(hackathon_id)
+            .unwrap()
+            .hackathon
+            .description,
+        "updated"
+    );
+    assert_eq!(detail.submissions[0].description, "updated");
+    let award = &detail.categories[0].awards[0];
+    assert_eq!(award.price.0, ONE_NEAR * 3 / 2);
+    assert!(award.is_awarded);
+    assert_eq!(award.winner.as_ref().unwrap().members.len(), 2);
+    assert_eq!(
+        contract.get_hackathon_summary(hackathon_id).unwrap().name,
+        "h"
+    );
+    let member = contract.get_user_information_by_id(accounts(2)).unwrap();
+    assert_eq!(member.bio.as_deref(), Some("bio"));
+
+    let updated: Value = execute(
+        &mut contract,
+        accounts(2),
+        END,
+        &client.update_member(Some("renamed"), None, None),
+    );
+    assert_eq!(updated["name"], "renamed");
+}
+
+#[test]
+fn views_decode_the_contract_results() {
+    let client = client();
+    testing_env!(VMContextBuilder::new()
+        .current_account_id(client.contract_id.clone())
+        .signer_account_id(accounts(0))
+        .predecessor_account_id(accounts(0))
+        .attached_deposit(ONE_NEAR)
+        .build());
+    let mut contract = HackathonContract::init(None, None);
+    contract.storage_deposit(None, None);
+    contract.add_member("alice".to_string(), None, None);
+    let hackathon_id = contract.add_hackathon(payload("h")).unwrap();
+
+    // The args of a view decode with the parameter types of the contract
+    let view = client.get_hackathon_summary(hackathon_id);
+    let args: Value = serde_json::from_slice(&view.args).unwrap();
+    assert_eq!(view.method_name, "get_hackathon_summary");
+    assert_eq!(args["hackathon_id"], hackathon_id);
+
+    let result = serde_json::to_vec(&contract.get_hackathon_summary(hackathon_id)).unwrap();
+    let summary = view.decode(&result).unwrap().unwrap();
+    assert_eq!(summary.id, hackathon_id);
+    assert_eq!(summary.phase, HackathonPhase::Upcoming);
+
+    let view = client.get_user_information_by_id(&accounts(0));
+    let result = serde_json::to_vec(&contract.get_user_information_by_id(accounts(0))).unwrap();
+    let member = view.decode(&result).unwrap().unwrap();
+    assert_eq!(member.created_hackathons[0].hackathon.name, "h");
+
+    let view = client.get_upcoming_hackathons(None, None, Some(10));
+    let result =
+        serde_json::to_vec(&contract.get_upcoming_hackathons(None, None, Some(10))).unwrap();
+    assert_eq!(view.decode(&result).unwrap().items.len(), 1);
+
+    let view = client.storage_balance_of(&accounts(1));
+    assert_eq!(view.decode(b"null").unwrap(), None);
+    assert!(view.decode(b"{}").is_err());
+}
+
+#[test]
+fn calls_recommend_gas_and_deposit() {
+    let client = client();
+
+    let call = client.join_hackathon(0);
+    assert_eq!(call.method_name, "join_hackathon");
+    assert_eq!(call.receiver_id, client.contract_id);
+    assert_eq!((call.gas, call.deposit), (CALL_GAS, 0));
+    assert_eq!(call.args, br#"{"hackathon_id":0}"#);
+
+    let call = client.submit_project(&ProjectSubmission::default());
+    assert_eq!((call.gas, call.deposit), (HEAVY_GAS, 0));
+
+    let call = client.fund_award(0, 1, 2, ONE_NEAR);
+    assert_eq!((call.gas, call.deposit), (CALL_GAS, ONE_NEAR));
+
+    let call = client.award_winner(0, 1, 2, ONE_NEAR);
+    assert_eq!((call.gas, call.deposit), (TRANSFER_GAS, ONE_NEAR));
+
+    let call = client.propose_award_payout(0, 1, 2, ONE_NEAR / 10);
+    assert_eq!((call.gas, call.deposit), (HEAVY_GAS, ONE_NEAR / 10));
+
+    let call = client.cancel_hackathon(0);
+    assert_eq!((call.gas, call.deposit), (HEAVY_GAS, 0));
+
+    let call = client.storage_withdraw(Some(ONE_NEAR));
+    assert_eq!((call.gas, call.deposit), (TRANSFER_GAS, ONE_YOCTO));
+    assert_eq!(
+        arg::<Option<near_sdk::json_types::U128>>(&call, "amount")
+            .unwrap()
+            .0,
+        ONE_NEAR
+    );
+
+    let call = client.accept_contract_owner().with_gas(HEAVY_GAS);
+    assert_eq!(call.args, b"{}");
+    assert_eq!(call.gas, HEAVY_GAS);
+}
+
+#[test]
+fn every_builder_sends_the_abi_arguments() {
+    let client = client();
+    let account = accounts(1);
+    let skills = ["rust".to_string()];
+    let code_hash = "11111111111111111111111111111111".parse().unwrap();
+    let calls = [
+        client.add_member("name", None, None),
+        client.update_member(None, None, None),
+        client.add_hackathon(&payload("h")),
+        client.update_hackathon(0, &HackathonUpdatePayload::default()),
+        client.cancel_hackathon(0),
+        client.add_category(0, "c"),
+        client.update_category(0, 1, "c"),
+        client.add_award(0, 1, "a", 1.0),
+        client.update_award(0, 1, 2, None, None),
+        client.add_organizer(0, &account),
+        client.remove_organizer(0, &account),
+        client.propose_owner(0, None),
+        client.accept_owner(0),
+        client.set_payout_dao(0, None),
+        client.join_hackathon(0),
+        client.leave_hackathon(0),
+        client.create_team(0, "t"),
+        client.invite_to_team(0, &account),
+        client.accept_team_invitation(0),
+        client.decline_team_invitation(0),
+        client.leave_team(0),
+        client.post_looking_for_team(0, &skills, "m"),
+        client.post_looking_for_members(0, &skills, "m"),
+        client.remove_post(0, None),
+        client.prune_posts(0),
+        client.submit_project(&ProjectSubmission::default()),
+        client.update_submission(0, &SubmissionUpdatePayload::default()),
+        client.anchor_commit(0, "abc"),
+        client.withdraw_submission(0, 1),
+        client.judge_winner(0, 1, 2, 3),
+        client.fund_award(0, 1, 2, ONE_NEAR),
+        client.award_winner(0, 1, 2, 0),
+        client.propose_award_payout(0, 1, 2, 0),
+        client.check_award_payout(0, 1, 2),
+        client.reset_award_payout(0, 1, 2),
+        client.storage_deposit(None, None, ONE_NEAR),
+        client.storage_withdraw(None),
+        client.propose_contract_owner(None),
+        client.accept_contract_owner(),
+        client.set_upgrade_delay(0),
+        client.stage_upgrade(code_hash),
+        client.cancel_upgrade(),
+    ];
+    let cursor = Some(CalendarCursor::Start(START, 0));
+    let views = [
+        view_args(client.storage_balance_of(&account)),
+        view_args(client.storage_balance_bounds()),
+        view_args(client.get_all_hackathons(None, None)),
+        view_args(client.get_all_members(None, None)),
+        view_args(client.get_hackathon_participants(0, None, None)),
+        view_args(client.get_hackathon_submissions(0, None, None)),
+        view_args(client.get_member_joined_hackathons(&account, None, None)),
+        view_args(client.get_member_created_hackathons(&account, None, None)),
+        view_args(client.get_hackathons_by_tag("near", None, None)),
+        view_args(client.get_hackathons_in_range(START, END, Some((START, 0)), None)),
+        view_args(client.get_upcoming_hackathons(None, cursor, None)),
+        view_args(client.get_ongoing_hackathons(None, cursor, None)),
+        view_args(client.get_past_hackathons(None, cursor, None)),
+        view_args(client.get_prize_totals_check(0)),
+        view_args(client.get_hackathon_categories(0, None, None)),
+        view_args(client.get_hackathon(0)),
+        view_args(client.get_hackathon_summary(0)),
+        view_args(client.get_hackathon_summaries(None, None)),
+        view_args(client.get_detail_hackathon_by_id(0)),
+        view_args(client.get_hackathon_edits(0, None, None)),
+        view_args(client.get_contract_owner()),
+        view_args(client.get_upgrade_delay()),
+        view_args(client.get_staged_upgrade()),
+        view_args(client.get_state_version()),
+        view_args(client.contract_source_metadata()),
+        view_args(client.get_team(0)),
+        view_args(client.get_hackathon_teams(0, None, None)),
+        view_args(client.get_member_invitations(&account, None, None)),
+        view_args(client.get_posts(0, None, None)),
+        view_args(client.get_post_matches(0, None, None)),
+        view_args(client.get_submission_revision(0, 1)),
+        view_args(client.get_submission_revisions(0, None, None)),
+        view_args(client.get_deadline_revision(0)),
+        view_args(client.get_user_information_by_id(&account)),
+    ];
+
+    // Every builder sends exactly the parameters of its method, and every method has a builder
+    let abi = abi_params();
+    let built = calls
+        .iter()
+        .map(|call| ("call", call.method_name, &call.args))
+        .chain(
+            views
+                .iter()
+                .map(|(method_name, args)| ("view", *method_name, args)),
+        );
+    let mut covered = BTreeSet::new();
+    for (kind, method_name, args) in built {
+        let (abi_kind, params) = abi
+            .get(method_name)
+            .unwrap_or_else(|| panic!("{} is not in the ABI", method_name));
+        assert_eq!(abi_kind, kind, "{}", method_name);
+        assert_eq!(&arg_names(method_name, args), params, "{}", method_name);
+        assert!(
+            covered.insert(method_name),
+            "{} is built twice",
+            method_name
+        );
+    }
+    let missing: Vec<_> = abi
+        .keys()
+        .filter(|name| !covered.contains(name.as_str()) && !NOT_BUILT.contains(&name.as_str()))
+        .collect();
+    assert!(missing.is_empty(), "no builder for {:?}", missing);
+}
+
+#[test]
+fn builder_arguments_decode_with_the_contract_types() {
+    let client = client();
+
+    let call = client.update_hackathon(
+        0,
+        &HackathonUpdatePayload {
+            end: Some(END),
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        arg::<HackathonUpdatePayload>(&call, "payload").end,
+        Some(END)
+    );
+
+    let call = client.update_submission(
+        3,
+        &SubmissionUpdatePayload {
+            categories: Some(vec![1]),
+            ..Default::default()
+        },
+    );
+    assert_eq!(arg::<u64>(&call, "submission_id"), 3);
+    assert_eq!(
+        arg::<SubmissionUpdatePayload>(&call, "payload").categories,
+        Some(vec![1])
+    );
+
+    let call = client.propose_owner(0, Some(&accounts(1)));
+    assert_eq!(
+        arg::<Option<AccountId>>(&call, "new_owner"),
+        Some(accounts(1))
+    );
+    let call = client.propose_owner(0, None);
+    assert_eq!(arg::<Option<AccountId>>(&call, "new_owner"), None);
+
+    let call = client.propose_award_payout(0, 1, 2, ONE_NEAR / 10);
+    assert_eq!(
+        (
+            arg::<u64>(&call, "hackathon_id"),
+            arg::<u64>(&call, "category_id"),
+            arg::<u64>(&call, "award_id")
+        ),
+        (0, 1, 2)
+    );
+
+    let view = client.get_hackathon(4);
+    let args: Value = serde_json::from_slice(&view.args).unwrap();
+    assert_eq!(args, json!({ "hackathon_id": 4 }));
+
+    let cursor = CalendarCursor::Prize(U128(ONE_NEAR), 4);
+    let view = client.get_past_hackathons(None, Some(cursor), Some(5));
+    let args: Value = serde_json::from_slice(&view.args).unwrap();
+    let after: Option<CalendarCursor> = serde_json::from_value(args["after"].clone()).unwrap();
+    assert_eq!(after, Some(cursor));
+
+    let view = client.get_hackathons_in_range(START, END, Some((BUILDING, 4)), None);
+    let args: Value = serde_json::from_slice(&view.args).unwrap();
+    let after: Option<(Timestamp, u64)> = serde_json::from_value(args["after"].clone()).unwrap();
+    assert_eq!(after, Some((BUILDING, 4)));
+}
@@ -0,0 +1,615 @@
+use std::io::BufRead;
+use std::path::Path;
+
+use rusqlite::{params, Connection};
+
+use crate::error::Error;
+use crate::event::{parse_line, Event};
+
+// Every table is keyed by the contract ids, so applying an event twice leaves the same rows
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS members (
+    account_id TEXT PRIMARY KEY
+);
+CREATE TABLE IF NOT EXISTS hackathons (
+    id INTEGER PRIMARY KEY,
+    owner_id TEXT NOT NULL,
+    pending_owner_id TEXT,
+    payout_dao_id TEXT,
+    start_time INTEGER NOT NULL,
+    end_time INTEGER NOT NULL,
+    cancelled INTEGER NOT NULL DEFAULT 0
+);
+CREATE TABLE IF NOT EXISTS organizers (
+    hackathon_id INTEGER NOT NULL,
+    account_id TEXT NOT NULL,
+    PRIMARY KEY (hackathon_id, account_id)
+);
+CREATE TABLE IF NOT EXISTS participants (
+    hackathon_id INTEGER NOT NULL,
+    account_id TEXT NOT NULL,
+    PRIMARY KEY (hackathon_id, account_id)
+);
+CREATE TABLE IF NOT EXISTS categories (
+    id INTEGER PRIMARY KEY,
+    hackathon_id INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS awards (
+    id INTEGER PRIMARY KEY,
+    hackathon_id INTEGER NOT NULL,
+    category_id INTEGER NOT NULL,
+    token TEXT,
+    amount TEXT,
+    funded TEXT NOT NULL DEFAULT '0',
+    winner_submission_id INTEGER,
+    receiver_id TEXT,
+    paid INTEGER NOT NULL DEFAULT 0,
+    payout_dao_id TEXT,
+    payout_proposal_id INTEGER
+);
+CREATE TABLE IF NOT EXISTS submissions (
+    id INTEGER PRIMARY KEY,
+    hackathon_id INTEGER NOT NULL,
+    team_id INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS submission_members (
+    submission_id INTEGER NOT NULL,
+    account_id TEXT NOT NULL,
+    PRIMARY KEY (submission_id, account_id)
+);
+CREATE TABLE IF NOT EXISTS submission_categories (
+    submission_id INTEGER NOT NULL,
+    category_id INTEGER NOT NULL,
+    PRIMARY KEY (submission_id, category_id)
+);
+";
+
+// Define the replay statistics structure
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ReplayStats {
+    pub lines: u64,
+    pub events: u64,
+}
+
+// Define the hackathon row structure
+#[derive(Debug, Clone, PartialEq)]
+pub struct HackathonRow {
+    pub id: u64,
+    pub owner_id: String,
+    pub pending_owner_id: Option<String>,
+    pub payout_dao_id: Option<String>,
+    pub start: u64,
+    pub end: u64,
+    pub cancelled: bool,
+    pub participants: u64,
+    pub submissions: u64,
+    // Sums of the award amounts and of their escrow, in the smallest unit of the token
+    pub total_prize: u128,
+    pub total_funded: u128,
+}
+
+// Define the award row structure
+#[derive(Debug, Clone, PartialEq)]
+pub struct AwardRow {
+    pub id: u64,
+    pub category_id: u64,
+    pub token: Option<String>,
+    pub amount: Option<String>,
+    pub funded: String,
+    pub winner_submission_id: Option<u64>,
+    pub receiver_id: Option<String>,
+    pub paid: bool,
+    // The DAO proposal paying the award while it is open
+    pub payout_dao_id: Option<String>,
+    pub payout_proposal_id: Option<u64>,
+}
+
+// Define the submission row structure
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubmissionRow {
+    pub id: u64,
+    pub team_id: u64,
+    pub members: Vec<String>,
+    pub categories: Vec<u64>,
+}
+
+// Define the Indexer structure - a SQLite database built from the contract events
+pub struct Indexer {
+    conn: Connection,
+}
+
+// Implement the Indexer structure
+impl Indexer {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self, Error> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self, Error> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Indexer { conn })
+    }
+
+    // Apply every event of a log in one transaction, nothing is written if a line is malformed
+    pub fn replay<R: BufRead>(&mut self, reader: R) -> Result<ReplayStats, Error> {
+        let tx = self.conn.transaction()?;
+        let mut stats = ReplayStats::default();
+
+        for line in reader.lines() {
+            let line = line?;
+            stats.lines += 1;
+            let event = parse_line(&line).map_err(|source| Error::Event {
+                line: stats.lines,
+                source,
+            })?;
+            if let Some(event) = event {
+                apply(&tx, &event)?;
+                stats.events += 1;
+            }
+        }
+
+        tx.commit()?;
+        Ok(stats)
+    }
+
+    pub fn apply(&self, event: &Event) -> Result<(), Error> {
+        apply(&self.conn, event)
+    }
+
+    pub fn members(&self) -> Result<Vec<String>, Error> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT account_id FROM members ORDER BY account_id")?;
+        let rows = stmt.query_map([], |row| row.get(0))?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    pub fn hackathons(&self) -> Result<Vec<HackathonRow>, Error> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, owner_id, pending_owner_id, payout_dao_id, start_time, end_time, cancelled,
+                (SELECT COUNT(*) FROM participants p WHERE p.hackathon_id = h.id),
+                (SELECT COUNT(*) FROM submissions s WHERE s.hackathon_id = h.id)
+            FROM hackathons h ORDER BY id",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(HackathonRow {
+                id: row.get(0)?,
+                owner_id: row.get(1)?,
+                pending_owner_id: row.get(2)?,
+                payout_dao_id: row.get(3)?,
+                start: row.get(4)?,
+                end: row.get(5)?,
+                cancelled: row.get(6)?,
+                participants: row.get(7)?,
+                submissions: row.get(8)?,
+                total_prize: 0,
+                total_funded: 0,
+            })
+        })?;
+
+        let mut hackathons = rows.collect::<Result<Vec<_>, _>>()?;
+        for hackathon in hackathons.iter_mut() {
+            let awards = self.awards(hackathon.id)?;
+            hackathon.total_prize = awards
+                .iter()
+                .filter_map(|award| award.amount.as_ref()?.parse::<u128>().ok())
+                .sum();
+            hackathon.total_funded = awards
+                .iter()
+                .filter_map(|award| award.funded.parse::<u128>().ok())
+                .sum();
+        }
+        Ok(hackathons)
+    }
+
+    pub fn hackathon(&self, hackathon_id: u64) -> Result<Option<HackathonRow>, Error> {
+        Ok(self
+            .hackathons()?
+            .into_iter()
+            .find(|hackathon| hackathon.id == hackathon_id))
+    }
+
+    pub fn organizers(&self, hackathon_id: u64) -> Result<Vec<String>, Error> {
+        let mut stmt = self.conn.prepare(
+            "SELECT account_id FROM organizers WHERE hackathon_id = ?1 ORDER BY account_id",
+        )?;
+        let rows = stmt.query_map([hackathon_id], |row| row.get(0))?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    pub fn participants(&self, hackathon_id: u64) -> Result<Vec<String>, Error> {
+        let mut stmt = self.conn.prepare(
+            "SELECT account_id FROM participants WHERE hackathon_id = ?1 ORDER BY account_id",
+        )?;
+        let rows = stmt.query_map([hackathon_id], |row| row.get(0))?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    pub fn awards(&self, hackathon_id: u64) -> Result<Vec<AwardRow>, Error> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, category_id, token, amount, funded, winner_submission_id, receiver_id, paid,
+                payout_dao_id, payout_proposal_id
+            FROM awards WHERE hackathon_id = ?1 ORDER BY id",
+        )?;
+        let rows = stmt.query_map([hackathon_id], |row| {
+            Ok(AwardRow {
+                id: row.get(0)?,
+                category_id: row.get(1)?,
+                token: row.get(2)?,
+                amount: row.get(3)?,
+                funded: row.get(4)?,
+                winner_submission_id: row.get(5)?,
+                receiver_id: row.get(6)?,
+                paid: row.get(7)?,
+                payout_dao_id: row.get(8)?,
+                payout_proposal_id: row.get(9)?,
+            })
+        })?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    pub fn submissions(&self, hackathon_id: u64) -> Result<Vec<SubmissionRow>, Error> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id, team_id FROM submissions WHERE hackathon_id = ?1 ORDER BY id")?;
+        let rows = stmt.query_map([hackathon_id], |row| {
+            Ok(SubmissionRow {
+                id: row.get(0)?,
+                team_id: row.get(1)?,
+                members: Vec::new(),
+                categories: Vec::new(),
+            })
+        })?;
+
+        let mut submissions = rows.collect::<Result<Vec<_>, _>>()?;
+        for submission in submissions.iter_mut() {
+            let mut stmt = self.conn.prepare_cached(
+                "SELECT account_id FROM submission_members WHERE submission_id = ?1
+                ORDER BY account_id",
+            )?;
+            submission.members = stmt
+                .query_map([submission.id], |row| row.get(0))?
+                .collect::<Result<_, _>>()?;
+
+            let mut stmt = self.conn.prepare_cached(
+                "SELECT category_id FROM submission_categories WHERE submission_id = ?1
+                ORDER BY category_id",
+            )?;
+            submission.categories = stmt
+                .query_map([submission.id], |row| row.get(0))?
+                .collect::<Result<_, _>>()?;
+        }
+        Ok(submissions)
+    }
+}
+
+// Write one event. Rows are upserted, so replaying a log that was already applied is a no-op
+fn apply(conn: &Connection, event: &Event) -> Result<(), Error> {
+    match event {
+        Event::MemberRegistered { account_id } => {
+            conn.execute(
+                "INSERT OR IGNORE INTO members (account_id) VALUES (?1)",
+                [account_id],
+            )?;
+        }
+        Event::HackathonCreated {
+            hackathon_id,
+            owner_id,
+            start,
+            end,
+        } => {
+            conn.execute(
+                "INSERT INTO hackathons (id, owner_id, start_time, end_time)
+                VALUES (?1, ?2, ?3, ?4)
+                ON CONFLICT (id) DO UPDATE SET owner_id = excluded.owner_id,
+                    start_time = excluded.start_time, end_time = excluded.end_time",
+                params![hackathon_id, owner_id, start, end],
+            )?;
+        }
+        Event::CategoryAdded {
+            hackathon_id,
+            category_id,
+        } => {
+            conn.execute(
+                "INSERT OR REPLACE INTO categories (id, hackathon_id) VALUES (?1, ?2)",
+                params![category_id, hackathon_id],
+            )?;
+        }
+        Event::AwardAdded {
+            hackathon_id,
+            category_id,
+            award_id,
+            token,
+            amount,
+        } => {
+            conn.execute(
+                "INSERT INTO awards (id, hackathon_id, category_id, token, amount)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT (id) DO UPDATE SET hackathon_id = excluded.hackathon_id,
+                    category_id = excluded.category_id, token = excluded.token,
+                    amount = excluded.amount",
+                params![award_id, hackathon_id, category_id, token, amount],
+            )?;
+        }
+        Event::ParticipantJoined {
+            hackathon_id,
+            account_id,
+        } => {
+            conn.execute(
+                "INSERT OR IGNORE INTO participants (hackathon_id, account_id) VALUES (?1, ?2)",
+                params![hackathon_id, account_id],
+            )?;
+        }
+        Event::ProjectSubmitted {
+            hackathon_id,
+            submission_id,
+            team_id,
+            account_ids,
+            category_ids,
+        } => {
+            conn.execute(
+                "INSERT OR REPLACE INTO submissions (id, hackathon_id, team_id) VALUES (?1, ?2, ?3)",
+                params![submission_id, hackathon_id, team_id],
+            )?;
+            for account_id in account_ids {
+                conn.execute(
+                    "INSERT OR IGNORE INTO submission_members (submission_id, account_id)
+                    VALUES (?1, ?2)",
+                    params![submission_id, account_id],
+                )?;
+            }
+            for category_id in category_ids {
+                conn.execute(
+                    "INSERT OR IGNORE INTO submission_categories (submission_id, category_id)
+                    VALUES (?1, ?2)",
+                    params![submission_id, category_id],
+                )?;
+            }
+        }
+        Event::WinnerJudged {
+            hackathon_id,
+            category_id,
+            award_id,
+            submission_id,
+        } => {
+            conn.execute(
+                "INSERT INTO awards (id, hackathon_id, category_id, winner_submission_id)
+                VALUES (?1, ?2, ?3, ?4)
+                ON CONFLICT (id) DO UPDATE SET
+                    winner_submission_id = excluded.winner_submission_id",
+                params![award_id, hackathon_id, category_id, submission_id],
+            )?;
+        }
+        Event::AwardPaid {
+            hackathon_id,
+            category_id,
+            award_id,
+            submission_id,
+            receiver_id,
+            token,
+            amount,
+        } => {
+            // Paid by the owner the award is fully escrowed, a DAO pays it from its own funds
+            conn.execute(
+                "INSERT INTO awards (id, hackathon_id, category_id, token, amount, funded,
+                    winner_submission_id, receiver_id, paid)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?5, ?6, ?7, 1)
+                ON CONFLICT (id) DO UPDATE SET token = excluded.token,
+                    amount = excluded.amount,
+                    funded = CASE WHEN payout_proposal_id IS NULL THEN excluded.amount
+                        ELSE funded END,
+                    winner_submission_id = excluded.winner_submission_id,
+                    receiver_id = excluded.receiver_id, paid = 1,
+                    payout_dao_id = NULL, payout_proposal_id = NULL",
+                params![
+                    award_id,
+                    hackathon_id,
+                    category_id,
+                    token,
+                    amount,
+                    submission_id,
+                    receiver_id
+                ],
+            )?;
+        }
+        Event::HackathonUpdated {
+            hackathon_id,
+            start,
+            end,
+        } => {
+            conn.execute(
+                "UPDATE hackathons SET start_time = ?2, end_time = ?3 WHERE id = ?1",
+                params![hackathon_id, start, end],
+            )?;
+        }
+        // The escrow of every award went back to the owner
+        Event::HackathonCancelled { hackathon_id, .. } => {
+            conn.execute(
+                "UPDATE hackathons SET cancelled = 1 WHERE id = ?1",
+                [hackathon_id],
+            )?;
+            conn.execute(
+                "UPDATE awards SET funded = '0' WHERE hackathon_id = ?1",
+                [hackathon_id],
+            )?;
+        }
+        Event::AwardUpdated {
+            hackathon_id,
+            category_id,
+            award_id,
+            token,
+            amount,
+        } => {
+            conn.execute(
+                "INSERT INTO awards (id, hackathon_id, category_id, token, amount)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT (id) DO UPDATE SET token = excluded.token,
+                    amount = excluded.amount",
+                params![award_id, hackathon_id, category_id, token, amount],
+            )?;
+        }
+        Event::ParticipantLeft {
+            hackathon_id,
+            account_id,
+        } => {
+            conn.execute(
+                "DELETE FROM participants WHERE hackathon_id = ?1 AND account_id = ?2",
+                params![hackathon_id, account_id],
+            )?;
+        }
+        Event::SubmissionWithdrawn {
+            submission_id,
+            award_ids,
+            ..
+        } => {
+            conn.execute("DELETE FROM submissions WHERE id = ?1", [submission_id])?;
+            conn.execute(
+                "DELETE FROM submission_members WHERE submission_id = ?1",
+                [submission_id],
+            )?;
+            conn.execute(
+                "DELETE FROM submission_categories WHERE submission_id = ?1",
+                [submission_id],
+            )?;
+            for award_id in award_ids {
+                conn.execute(
+                    "UPDATE awards SET winner_submission_id = NULL WHERE id = ?1",
+                    [award_id],
+                )?;
+            }
+        }
+        Event::TeamMemberJoined {
+            account_id,
+            submission_ids,
+            ..
+        } => {
+            for submission_id in submission_ids {
+                conn.execute(
+                    "INSERT OR IGNORE INTO submission_members (submission_id, account_id)
+                    VALUES (?1, ?2)",
+                    params![submission_id, account_id],
+                )?;
+            }
+        }
+        Event::TeamMemberLeft {
+            account_id,
+            submission_ids,
+            ..
+        } => {
+            for submission_id in submission_ids {
+                conn.execute(
+                    "DELETE FROM submission_members WHERE submission_id = ?1 AND account_id = ?2",
+                    params![submission_id, account_id],
+                )?;
+            }
+        }
+        Event::AwardFunded {
+            hackathon_id,
+            category_id,
+            award_id,
+            token,
+            funded,
+            ..
+        } => {
+            conn.execute(
+                "INSERT INTO awards (id, hackathon_id, category_id, token, funded)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT (id) DO UPDATE SET token = excluded.token,
+                    funded = excluded.funded",
+                params![award_id, hackathon_id, category_id, token, funded],
+            )?;
+        }
+        Event::AwardPayoutProposed {
+            hackathon_id,
+            category_id,
+            award_id,
+            dao_id,
+            proposal_id,
+        } => {
+            conn.execute(
+                "INSERT INTO awards (id, hackathon_id, category_id, payout_dao_id,
+                    payout_proposal_id)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT (id) DO UPDATE SET payout_dao_id = excluded.payout_dao_id,
+                    payout_proposal_id = excluded.payout_proposal_id",
+                params![award_id, hackathon_id, category_id, dao_id, proposal_id],
+            )?;
+        }
+        // A later proposal of the award is left open
+        Event::AwardPayoutClosed {
+            award_id,
+            proposal_id,
+            ..
+        } => {
+            conn.execute(
+                "UPDATE awards SET payout_dao_id = NULL, payout_proposal_id = NULL
+                WHERE id = ?1 AND payout_proposal_id = ?2",
+                params![award_id, proposal_id],
+            )?;
+        }
+        Event::OwnerProposed {
+            hackathon_id,
+            owner_id,
+        } => {
+            conn.execute(
+                "UPDATE hackathons SET pending_owner_id = ?2 WHERE id = ?1",
+                params![hackathon_id, owner_id],
+            )?;
+        }
+        // The new owner stops being an organizer
+        Event::OwnerAccepted {
+            hackathon_id,
+            owner_id,
+            ..
+        } => {
+            conn.execute(
+                "UPDATE hackathons SET owner_id = ?2, pending_owner_id = NULL WHERE id = ?1",
+                params![hackathon_id, owner_id],
+            )?;
+            conn.execute(
+                "DELETE FROM organizers WHERE hackathon_id = ?1 AND account_id = ?2",
+                params![hackathon_id, owner_id],
+            )?;
+        }
+        Event::OrganizerAdded {
+            hackathon_id,
+            account_id,
+        } => {
+            conn.execute(
+                "INSERT OR IGNORE INTO organizers (hackathon_id, account_id) VALUES (?1, ?2)",
+                params![hackathon_id, account_id],
+            )?;
+        }
+        Event::OrganizerRemoved {
+            hackathon_id,
+            account_id,
+        } => {
+            conn.execute(
+                "DELETE FROM organizers WHERE hackathon_id = ?1 AND account_id = ?2",
+                params![hackathon_id, account_id],
+            )?;
+        }
+        Event::PayoutDaoSet {
+            hackathon_id,
+            dao_id,
+        } => {
+            conn.execute(
+                "UPDATE hackathons SET payout_dao_id = ?2 WHERE id = ?1",
+                params![hackathon_id, dao_id],
+            )?;
+        }
+        // Category names, teams, invitations, posts and submission contents are not indexed
+        Event::CategoryUpdated { .. }
+        | Event::TeamCreated { .. }
+        | Event::TeamMemberInvited { .. }
+        | Event::TeamInvitationDeclined { .. }
+        | Event::PostAdded { .. }
+        | Event::PostRemoved { .. }
+        | Event::SubmissionUpdated { .. }
+        | Event::CommitAnchored { .. } => (),
+    }
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use std::fmt;
+use std::io;
+
+// Define the errors of the indexer
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Sql(rusqlite::Error),
+    // A log line with the event prefix that is not a valid event
+    Event {
+        line: u64,
+        source: serde_json::Error,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "io error: {}", err),
+            Error::Sql(err) => write!(f, "database error: {}", err),
+            Error::Event { line, source } => {
+                write!(f, "invalid event on line {}: {}", line, source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Sql(err) => Some(err),
+            Error::Event { source, .. } => Some(source),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(err: rusqlite::Error) -> Self {
+        Error::Sql(err)
+    }
+}
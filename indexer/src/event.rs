@@ -0,0 +1,241 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+pub const EVENT_PREFIX: &str = "EVENT_JSON:";
+pub const EVENT_STANDARD: &str = "hackathon";
+
+// Names of the events in `Event`. Other events of the standard come from a newer contract and are
+// skipped, a known event that does not decode is an error
+const KNOWN_EVENTS: &[&str] = &[
+    "member_registered",
+    "hackathon_created",
+    "category_added",
+    "award_added",
+    "participant_joined",
+    "project_submitted",
+    "winner_judged",
+    "award_paid",
+    "hackathon_updated",
+    "hackathon_cancelled",
+    "category_updated",
+    "award_updated",
+    "participant_left",
+    "submission_withdrawn",
+    "team_created",
+    "team_member_joined",
+    "team_member_left",
+    "award_funded",
+    "award_payout_proposed",
+    "award_payout_closed",
+    "owner_proposed",
+    "owner_accepted",
+    "organizer_added",
+    "organizer_removed",
+    "payout_dao_set",
+    "submission_updated",
+    "commit_anchored",
+    "team_member_invited",
+    "team_invitation_declined",
+    "post_added",
+    "post_removed",
+];
+
+// Define the NEP-297 envelope of a log line, `data` is decoded once the event is known
+#[derive(Deserialize, Debug)]
+struct EventLog {
+    standard: String,
+    event: String,
+    #[serde(default)]
+    data: Value,
+}
+
+// Define the events of the contract, mirroring `HackathonEvent`. Amounts stay strings, a u128
+// does not fit SQLite integers
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Event {
+    MemberRegistered {
+        account_id: String,
+    },
+    HackathonCreated {
+        hackathon_id: u64,
+        owner_id: String,
+        start: u64,
+        end: u64,
+    },
+    CategoryAdded {
+        hackathon_id: u64,
+        category_id: u64,
+    },
+    AwardAdded {
+        hackathon_id: u64,
+        category_id: u64,
+        award_id: u64,
+        token: String,
+        amount: String,
+    },
+    ParticipantJoined {
+        hackathon_id: u64,
+        account_id: String,
+    },
+    ProjectSubmitted {
+        hackathon_id: u64,
+        submission_id: u64,
+        team_id: u64,
+        account_ids: Vec<String>,
+        category_ids: Vec<u64>,
+    },
+    WinnerJudged {
+        hackathon_id: u64,
+        category_id: u64,
+        award_id: u64,
+        submission_id: u64,
+    },
+    AwardPaid {
+        hackathon_id: u64,
+        category_id: u64,
+        award_id: u64,
+        submission_id: u64,
+        receiver_id: String,
+        token: String,
+        amount: String,
+    },
+    HackathonUpdated {
+        hackathon_id: u64,
+        start: u64,
+        end: u64,
+    },
+    HackathonCancelled {
+        hackathon_id: u64,
+        refund: String,
+    },
+    CategoryUpdated {
+        hackathon_id: u64,
+        category_id: u64,
+    },
+    AwardUpdated {
+        hackathon_id: u64,
+        category_id: u64,
+        award_id: u64,
+        token: String,
+        amount: String,
+    },
+    ParticipantLeft {
+        hackathon_id: u64,
+        account_id: String,
+    },
+    SubmissionWithdrawn {
+        hackathon_id: u64,
+        submission_id: u64,
+        team_id: u64,
+        award_ids: Vec<u64>,
+    },
+    TeamCreated {
+        hackathon_id: u64,
+        team_id: u64,
+        account_id: String,
+    },
+    TeamMemberJoined {
+        hackathon_id: u64,
+        team_id: u64,
+        account_id: String,
+        submission_ids: Vec<u64>,
+    },
+    TeamMemberLeft {
+        hackathon_id: u64,
+        team_id: u64,
+        account_id: String,
+        submission_ids: Vec<u64>,
+    },
+    AwardFunded {
+        hackathon_id: u64,
+        category_id: u64,
+        award_id: u64,
+        token: String,
+        amount: String,
+        funded: String,
+    },
+    AwardPayoutProposed {
+        hackathon_id: u64,
+        category_id: u64,
+        award_id: u64,
+        dao_id: String,
+        proposal_id: u64,
+    },
+    AwardPayoutClosed {
+        hackathon_id: u64,
+        category_id: u64,
+        award_id: u64,
+        proposal_id: u64,
+        status: String,
+    },
+    OwnerProposed {
+        hackathon_id: u64,
+        owner_id: Option<String>,
+    },
+    OwnerAccepted {
+        hackathon_id: u64,
+        old_owner_id: String,
+        owner_id: String,
+    },
+    OrganizerAdded {
+        hackathon_id: u64,
+        account_id: String,
+    },
+    OrganizerRemoved {
+        hackathon_id: u64,
+        account_id: String,
+    },
+    PayoutDaoSet {
+        hackathon_id: u64,
+        dao_id: Option<String>,
+    },
+    SubmissionUpdated {
+        hackathon_id: u64,
+        submission_id: u64,
+        revision: u32,
+    },
+    CommitAnchored {
+        hackathon_id: u64,
+        submission_id: u64,
+        revision: u32,
+        commit_hash: String,
+    },
+    TeamMemberInvited {
+        hackathon_id: u64,
+        team_id: u64,
+        account_id: String,
+    },
+    TeamInvitationDeclined {
+        hackathon_id: u64,
+        team_id: u64,
+        account_id: String,
+    },
+    PostAdded {
+        hackathon_id: u64,
+        author: String,
+        team_id: Option<u64>,
+    },
+    PostRemoved {
+        hackathon_id: u64,
+        author: String,
+        team_id: Option<u64>,
+    },
+}
+
+// Parse a log line. Lines without an event, events of other standards and events this indexer
+// does not know yet are skipped with `Ok(None)`
+pub fn parse_line(line: &str) -> Result<Option<Event>, serde_json::Error> {
+    let json = match line.trim().strip_prefix(EVENT_PREFIX) {
+        Some(json) => json,
+        None => return Ok(None),
+    };
+    let log: EventLog = serde_json::from_str(json)?;
+    if log.standard != EVENT_STANDARD || !KNOWN_EVENTS.contains(&log.event.as_str()) {
+        return Ok(None);
+    }
+
+    let mut tagged = serde_json::Map::new();
+    tagged.insert(log.event, log.data);
+    serde_json::from_value(Value::Object(tagged)).map(Some)
+}
@@ -0,0 +1,10 @@
+// Offline indexer for the hackathon contract. It replays the NEP-297 `EVENT_JSON:` log lines of
+// the contract into a SQLite database that can be queried without an RPC node.
+
+mod db;
+mod error;
+mod event;
+
+pub use db::{AwardRow, HackathonRow, Indexer, ReplayStats, SubmissionRow};
+pub use error::Error;
+pub use event::{parse_line, Event, EVENT_PREFIX, EVENT_STANDARD};
@@ -0,0 +1,142 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::process;
+
+use hack_near_indexer::{Error, Indexer};
+
+const USAGE: &str = "usage: hack-near-indexer <DATABASE> <COMMAND>
+
+commands:
+    replay [FILE]                 apply the event log lines of FILE, or stdin without FILE or with -
+    hackathons                    list every hackathon with its counts, prize and escrow totals
+    hackathon <ID>                show a hackathon with its organizers, participants and awards
+    members                       list every member
+    submissions <HACKATHON_ID>    list the submissions of a hackathon";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() < 2 {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    if let Err(err) = run(&args[0], &args[1], &args[2..]) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn run(database: &str, command: &str, args: &[String]) -> Result<(), Error> {
+    let mut indexer = Indexer::open(database)?;
+
+    match (command, args) {
+        ("replay", []) => print_replay(&mut indexer, None)?,
+        ("replay", [file]) if file == "-" => print_replay(&mut indexer, None)?,
+        ("replay", [file]) => print_replay(&mut indexer, Some(file))?,
+        ("hackathons", []) => {
+            for hackathon in indexer.hackathons()? {
+                println!(
+                    "{}\t{}\t{}\t{}\tcancelled={}\tparticipants={}\tsubmissions={}\tprize={}\tfunded={}",
+                    hackathon.id,
+                    hackathon.owner_id,
+                    hackathon.start,
+                    hackathon.end,
+                    hackathon.cancelled,
+                    hackathon.participants,
+                    hackathon.submissions,
+                    hackathon.total_prize,
+                    hackathon.total_funded
+                );
+            }
+        }
+        ("hackathon", [id]) => {
+            let hackathon_id = parse_id(id);
+            let hackathon = match indexer.hackathon(hackathon_id)? {
+                Some(hackathon) => hackathon,
+                None => {
+                    eprintln!("hackathon {} not indexed", hackathon_id);
+                    process::exit(1);
+                }
+            };
+            println!("id\t{}", hackathon.id);
+            println!("owner\t{}", hackathon.owner_id);
+            if let Some(pending_owner_id) = &hackathon.pending_owner_id {
+                println!("pending owner\t{}", pending_owner_id);
+            }
+            if let Some(payout_dao_id) = &hackathon.payout_dao_id {
+                println!("payout dao\t{}", payout_dao_id);
+            }
+            println!("start\t{}", hackathon.start);
+            println!("end\t{}", hackathon.end);
+            println!("cancelled\t{}", hackathon.cancelled);
+            println!("prize\t{}", hackathon.total_prize);
+            println!("funded\t{}", hackathon.total_funded);
+            for account_id in indexer.organizers(hackathon_id)? {
+                println!("organizer\t{}", account_id);
+            }
+            for account_id in indexer.participants(hackathon_id)? {
+                println!("participant\t{}", account_id);
+            }
+            for award in indexer.awards(hackathon_id)? {
+                println!(
+                    "award\t{}\tcategory={}\tamount={}\tfunded={}\ttoken={}\twinner={}\tpaid={}\tproposal={}",
+                    award.id,
+                    award.category_id,
+                    award.amount.unwrap_or_default(),
+                    award.funded,
+                    award.token.unwrap_or_default(),
+                    award
+                        .winner_submission_id
+                        .map_or_else(String::new, |id| id.to_string()),
+                    award.paid,
+                    award
+                        .payout_proposal_id
+                        .map_or_else(String::new, |id| id.to_string())
+                );
+            }
+        }
+        ("members", []) => {
+            for account_id in indexer.members()? {
+                println!("{}", account_id);
+            }
+        }
+        ("submissions", [id]) => {
+            for submission in indexer.submissions(parse_id(id))? {
+                println!(
+                    "{}\tteam={}\tmembers={}\tcategories={}",
+                    submission.id,
+                    submission.team_id,
+                    submission.members.join(","),
+                    submission
+                        .categories
+                        .iter()
+                        .map(|id| id.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                );
+            }
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    }
+    Ok(())
+}
+
+fn print_replay(indexer: &mut Indexer, file: Option<&String>) -> Result<(), Error> {
+    let stats = match file {
+        Some(file) => indexer.replay(BufReader::new(File::open(file)?))?,
+        None => indexer.replay(io::stdin().lock())?,
+    };
+    println!("{} events from {} lines", stats.events, stats.lines);
+    Ok(())
+}
+
+fn parse_id(id: &str) -> u64 {
+    id.parse().unwrap_or_else(|_| {
+        eprintln!("invalid id: {}", id);
+        process::exit(2);
+    })
+}
@@ -0,0 +1,211 @@
+use std::fs::{self, File};
+use std::io::BufReader;
+
+use hack_near_indexer::{parse_line, Error, Event, Indexer, ReplayStats};
+
+// The fixtures are logs of a contract run, written by a unit test of the contract
+const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/events.log");
+// Continues `events.log` with updates, a cancelled hackathon, a withdrawn submission, a DAO payout
+// and an ownership transfer
+const REMOVALS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/removals.log");
+const ONE_NEAR: u128 = 1_000_000_000_000_000_000_000_000;
+
+fn replay_fixture(indexer: &mut Indexer) -> ReplayStats {
+    indexer
+        .replay(BufReader::new(File::open(FIXTURE).unwrap()))
+        .unwrap()
+}
+
+// The lines of `removals.log` up to the first `event`
+fn removals_through(event: &str) -> String {
+    let log = fs::read_to_string(REMOVALS).unwrap();
+    let event = format!("\"event\":\"{}\"", event);
+    let end = log.lines().position(|line| line.contains(&event)).unwrap();
+    log.lines()
+        .take(end + 1)
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+#[test]
+fn replay_builds_tables() {
+    let mut indexer = Indexer::open_in_memory().unwrap();
+    let stats = replay_fixture(&mut indexer);
+    assert_eq!(
+        stats,
+        ReplayStats {
+            lines: 21,
+            events: 21
+        }
+    );
+
+    assert_eq!(
+        indexer.members().unwrap(),
+        vec!["alice", "bob", "charlie", "danny", "eugene"]
+    );
+    let hackathon = indexer.hackathon(0).unwrap().unwrap();
+    assert_eq!(hackathon.owner_id, "alice");
+    assert_eq!(hackathon.pending_owner_id, None);
+    assert_eq!(hackathon.participants, 3);
+    assert_eq!(hackathon.submissions, 2);
+    assert_eq!(hackathon.total_prize, ONE_NEAR * 3 / 2);
+    assert_eq!(hackathon.total_funded, ONE_NEAR / 2);
+    assert_eq!(indexer.organizers(0).unwrap(), vec!["danny"]);
+
+    let awards = indexer.awards(0).unwrap();
+    assert_eq!(awards[0].funded, "0");
+    assert_eq!(awards[1].funded, (ONE_NEAR / 2).to_string());
+    assert!(!awards[0].paid);
+
+    let submissions = indexer.submissions(0).unwrap();
+    assert_eq!(submissions[0].members, vec!["bob", "charlie"]);
+    assert_eq!(submissions[0].categories, vec![0]);
+    assert_eq!(submissions[1].members, vec!["eugene"]);
+}
+
+#[test]
+fn replay_skips_other_lines() {
+    let mut indexer = Indexer::open_in_memory().unwrap();
+    let log = "EVENT_JSON:{\"standard\":\"nep171\",\"version\":\"1.0.0\",\"event\":\"nft_mint\",\"data\":[{\"owner_id\":\"bob\",\"token_ids\":[\"1\"]}]}\nTeam 0 created\nEVENT_JSON:{\"standard\":\"hackathon\",\"version\":\"1.0.0\",\"event\":\"member_registered\",\"data\":{\"account_id\":\"alice\"}}\n";
+
+    let stats = indexer.replay(log.as_bytes()).unwrap();
+    assert_eq!(
+        stats,
+        ReplayStats {
+            lines: 3,
+            events: 1
+        }
+    );
+    assert_eq!(indexer.members().unwrap(), vec!["alice"]);
+}
+
+#[test]
+fn replay_mirrors_removals() {
+    let mut indexer = Indexer::open_in_memory().unwrap();
+    replay_fixture(&mut indexer);
+    for _ in 0..2 {
+        indexer
+            .replay(BufReader::new(File::open(REMOVALS).unwrap()))
+            .unwrap();
+
+        let hackathon = indexer.hackathon(0).unwrap().unwrap();
+        assert_eq!(hackathon.end, 15_000);
+        assert!(!hackathon.cancelled);
+        assert_eq!(hackathon.owner_id, "danny");
+        assert_eq!(hackathon.pending_owner_id, None);
+        assert_eq!(hackathon.payout_dao_id.as_deref(), Some("dao.near"));
+        assert_eq!(hackathon.total_prize, ONE_NEAR * 3);
+        assert_eq!(hackathon.total_funded, ONE_NEAR / 2);
+        assert!(indexer.organizers(0).unwrap().is_empty());
+        assert_eq!(
+            indexer.participants(0).unwrap(),
+            vec!["bob", "charlie", "eugene"]
+        );
+
+        let cancelled = indexer.hackathon(1).unwrap().unwrap();
+        assert!(cancelled.cancelled);
+        assert_eq!(cancelled.total_funded, 0);
+
+        let submissions = indexer.submissions(0).unwrap();
+        assert_eq!(submissions.len(), 1);
+        assert_eq!(submissions[0].id, 0);
+        assert_eq!(submissions[0].members, vec!["bob"]);
+
+        // The DAO paid the first award, the escrow of the second one is kept
+        let awards = indexer.awards(0).unwrap();
+        assert_eq!(awards[0].winner_submission_id, Some(0));
+        assert_eq!(awards[0].receiver_id.as_deref(), Some("bob"));
+        assert!(awards[0].paid);
+        assert_eq!(awards[0].funded, "0");
+        assert_eq!(awards[0].payout_proposal_id, None);
+        assert_eq!(awards[1].winner_submission_id, None);
+        assert_eq!(awards[1].funded, (ONE_NEAR / 2).to_string());
+    }
+}
+
+#[test]
+fn open_proposals_are_mirrored() {
+    let mut indexer = Indexer::open_in_memory().unwrap();
+    replay_fixture(&mut indexer);
+
+    let log = removals_through("award_payout_proposed");
+    indexer.replay(log.as_bytes()).unwrap();
+    let award = &indexer.awards(0).unwrap()[0];
+    assert_eq!(award.payout_dao_id.as_deref(), Some("dao.near"));
+    assert_eq!(award.payout_proposal_id, Some(7));
+
+    let log = removals_through("award_payout_closed");
+    indexer.replay(log.as_bytes()).unwrap();
+    let award = &indexer.awards(0).unwrap()[0];
+    assert_eq!(award.payout_proposal_id, None);
+    assert!(!award.paid);
+
+    let log = removals_through("owner_proposed");
+    indexer.replay(log.as_bytes()).unwrap();
+    let hackathon = indexer.hackathon(0).unwrap().unwrap();
+    assert_eq!(hackathon.owner_id, "alice");
+    assert_eq!(hackathon.pending_owner_id.as_deref(), Some("danny"));
+}
+
+#[test]
+fn team_changes_follow_the_submissions() {
+    let mut indexer = Indexer::open_in_memory().unwrap();
+    replay_fixture(&mut indexer);
+    let log = "EVENT_JSON:{\"standard\":\"hackathon\",\"version\":\"1.0.0\",\"event\":\"team_member_left\",\"data\":{\"hackathon_id\":0,\"team_id\":0,\"account_id\":\"charlie\",\"submission_ids\":[0]}}\nEVENT_JSON:{\"standard\":\"hackathon\",\"version\":\"1.0.0\",\"event\":\"team_member_joined\",\"data\":{\"hackathon_id\":0,\"team_id\":0,\"account_id\":\"alice\",\"submission_ids\":[0]}}\n";
+
+    indexer.replay(log.as_bytes()).unwrap();
+    assert_eq!(
+        indexer.submissions(0).unwrap()[0].members,
+        vec!["alice", "bob"]
+    );
+}
+
+#[test]
+fn replay_is_idempotent() {
+    let mut indexer = Indexer::open_in_memory().unwrap();
+    replay_fixture(&mut indexer);
+    let hackathons = indexer.hackathons().unwrap();
+    let awards = indexer.awards(0).unwrap();
+    let submissions = indexer.submissions(0).unwrap();
+
+    replay_fixture(&mut indexer);
+    assert_eq!(indexer.hackathons().unwrap(), hackathons);
+    assert_eq!(indexer.awards(0).unwrap(), awards);
+    assert_eq!(indexer.submissions(0).unwrap(), submissions);
+    assert_eq!(indexer.members().unwrap().len(), 5);
+}
+
+#[test]
+fn malformed_event_aborts_the_replay() {
+    let mut indexer = Indexer::open_in_memory().unwrap();
+    let log = "EVENT_JSON:{\"standard\":\"hackathon\",\"version\":\"1.0.0\",\"event\":\"member_registered\",\"data\":{\"account_id\":\"alice.near\"}}\nEVENT_JSON:{\"standard\":\"hackathon\",\"event\":\"member_registered\",\"data\":{}}\n";
+
+    match indexer.replay(log.as_bytes()) {
+        Err(Error::Event { line, .. }) => assert_eq!(line, 2),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert!(indexer.members().unwrap().is_empty());
+}
+
+#[test]
+fn parse_line_reads_contract_events() {
+    let line = "EVENT_JSON:{\"standard\":\"hackathon\",\"version\":\"1.0.0\",\"event\":\"participant_joined\",\"data\":{\"hackathon_id\":3,\"account_id\":\"bob.near\"}}";
+    assert_eq!(
+        parse_line(line).unwrap(),
+        Some(Event::ParticipantJoined {
+            hackathon_id: 3,
+            account_id: "bob.near".to_string(),
+        })
+    );
+    assert_eq!(parse_line("plain log").unwrap(), None);
+}
+
+#[test]
+fn unknown_events_are_skipped_by_name() {
+    // Data of an event a newer contract added is not looked at
+    let line = "EVENT_JSON:{\"standard\":\"hackathon\",\"version\":\"1.1.0\",\"event\":\"hackathon_archived\",\"data\":[]}";
+    assert_eq!(parse_line(line).unwrap(), None);
+
+    let line = "EVENT_JSON:{\"standard\":\"hackathon\",\"version\":\"1.0.0\",\"event\":\"participant_left\",\"data\":{\"hackathon_id\":\"x\"}}";
+    assert!(parse_line(line).is_err());
+}
@@ -0,0 +1,146 @@
+// A stand-in for a SputnikDAO v2 with the proposal methods the hackathon contract calls. Every
+// vote decides the proposal, there is no council or quorum.
+
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near_bindgen, AccountId, PanicOnDefault, Promise};
+use schemars::JsonSchema;
+
+// Define the proposal input, as SputnikDAO takes it
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProposalInput {
+    pub description: String,
+    pub kind: ProposalKind,
+}
+
+// Only transfers are supported, an empty `token_id` is NEAR
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ProposalKind {
+    Transfer {
+        token_id: String,
+        receiver_id: AccountId,
+        amount: U128,
+        msg: Option<String>,
+    },
+}
+
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ProposalStatus {
+    InProgress,
+    Approved,
+    Rejected,
+    Removed,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Action {
+    VoteApprove,
+    VoteReject,
+    VoteRemove,
+}
+
+// Define the proposal (response) - the fields of a SputnikDAO proposal the tests look at
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: AccountId,
+    pub description: String,
+    pub kind: ProposalKind,
+    pub status: ProposalStatus,
+}
+
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+pub struct MockDao {
+    pub proposal_bond: u128,
+    pub proposals: Vec<Proposal>,
+}
+
+#[near_bindgen]
+impl MockDao {
+    #[init]
+    pub fn new(proposal_bond: U128) -> Self {
+        Self {
+            proposal_bond: proposal_bond.0,
+            proposals: Vec::new(),
+        }
+    }
+
+    #[payable]
+    pub fn add_proposal(&mut self, proposal: ProposalInput) -> u64 {
+        assert!(
+            env::attached_deposit() >= self.proposal_bond,
+            "ERR_MIN_BOND"
+        );
+
+        let id = self.proposals.len() as u64;
+        self.proposals.push(Proposal {
+            id,
+            proposer: env::predecessor_account_id(),
+            description: proposal.description,
+            kind: proposal.kind,
+            status: ProposalStatus::InProgress,
+        });
+        id
+    }
+
+    // An approved transfer is paid right away
+    pub fn act_proposal(&mut self, id: u64, action: Action) {
+        let proposal = self
+            .proposals
+            .get_mut(id as usize)
+            .expect("ERR_NO_PROPOSAL");
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::InProgress,
+            "ERR_PROPOSAL_NOT_READY_FOR_VOTE"
+        );
+
+        proposal.status = match action {
+            Action::VoteApprove => ProposalStatus::Approved,
+            Action::VoteReject => ProposalStatus::Rejected,
+            Action::VoteRemove => ProposalStatus::Removed,
+        };
+        if proposal.status == ProposalStatus::Approved {
+            let ProposalKind::Transfer {
+                receiver_id,
+                amount,
+                ..
+            } = &proposal.kind;
+            Promise::new(receiver_id.clone()).transfer(amount.0);
+        }
+    }
+
+    pub fn get_proposal(&self, id: u64) -> Proposal {
+        self.proposals
+            .get(id as usize)
+            .cloned()
+            .expect("ERR_NO_PROPOSAL")
+    }
+
+    pub fn get_last_proposal_id(&self) -> u64 {
+        self.proposals.len() as u64
+    }
+}
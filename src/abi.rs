@@ -0,0 +1,49 @@
+use near_abi::__private::ChunkedAbiEntry;
+use near_abi::{AbiMetadata, AbiRoot};
+use near_sdk::serde_json;
+
+use crate::version::CONTRACT_VERSION;
+
+// `near_bindgen` exports the ABI of every impl block under the name of its first public method,
+// a new impl block has to be added here
+#[allow(improper_ctypes)]
+extern "C" {
+    fn __near_abi_init() -> (*const u8, usize);
+    fn __near_abi_add_member() -> (*const u8, usize);
+    fn __near_abi_migrate() -> (*const u8, usize);
+    fn __near_abi_propose_contract_owner() -> (*const u8, usize);
+    fn __near_abi_fund_award() -> (*const u8, usize);
+    fn __near_abi_propose_award_payout() -> (*const u8, usize);
+    fn __near_abi_storage_deposit() -> (*const u8, usize);
+    fn __near_abi_get_all_hackathons() -> (*const u8, usize);
+}
+
+// Build the ABI of every public method, with the JSON Schema of their arguments and results
+pub fn contract_abi() -> AbiRoot {
+    let chunks = unsafe {
+        [
+            __near_abi_init(),
+            __near_abi_add_member(),
+            __near_abi_migrate(),
+            __near_abi_propose_contract_owner(),
+            __near_abi_fund_award(),
+            __near_abi_propose_award_payout(),
+            __near_abi_storage_deposit(),
+            __near_abi_get_all_hackathons(),
+        ]
+    };
+
+    let entries = chunks.into_iter().map(|(ptr, len)| {
+        // The generated functions hand over a leaked vector whose length is its capacity
+        let data = unsafe { Vec::from_raw_parts(ptr as *mut u8, len, len) };
+        serde_json::from_slice::<ChunkedAbiEntry>(&data).expect("Invalid ABI chunk")
+    });
+
+    ChunkedAbiEntry::combine(entries)
+        .expect("ABI chunks of different schema versions")
+        .into_abi_root(AbiMetadata {
+            name: Some(env!("CARGO_PKG_NAME").to_string()),
+            version: Some(CONTRACT_VERSION.to_string()),
+            ..Default::default()
+        })
+}
\ No newline at end of file
@@ -0,0 +1,153 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::AccountId;
+
+const MAX_LABEL_LEN: usize = 64;
+const MAX_VALUE_LEN: usize = 512;
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+// Define the kind of a submission artifact, which decides how its value is validated
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactKind {
+    SourceRepo,
+    DemoUrl,
+    Video,
+    PitchDeck,
+    ContractAccount,
+    IpfsCid,
+    // Any other https link. Migrated links of no recognisable shape are kept as they were
+    Other,
+}
+
+// Define the Artifact structure - a typed link attached to a submission
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct Artifact {
+    pub kind: ArtifactKind,
+    pub label: String,
+    pub value: String,
+}
+
+// Implement the Artifact structure
+impl Artifact {
+    // Convert an untyped `link` string of older submissions, guessing the kind from its shape
+    pub fn from_link(link: String) -> Self {
+        let kind = if let Some(host) = url_host(&link) {
+            if is_on_domain(&host, &["github.com", "gitlab.com", "bitbucket.org"]) {
+                ArtifactKind::SourceRepo
+            } else if is_on_domain(&host, &["youtube.com", "youtu.be", "vimeo.com", "loom.com"]) {
+                ArtifactKind::Video
+            } else {
+                ArtifactKind::DemoUrl
+            }
+        } else if is_ipfs_cid(&link) {
+            ArtifactKind::IpfsCid
+        } else if link.parse::<AccountId>().is_ok() {
+            ArtifactKind::ContractAccount
+        } else {
+            ArtifactKind::Other
+        };
+
+        Artifact {
+            kind,
+            label: "link".to_string(),
+            value: link,
+        }
+    }
+
+    pub fn assert_valid(&self) {
+        assert!(
+            !self.label.is_empty() && self.label.len() <= MAX_LABEL_LEN,
+            "Invalid artifact label: must be 1 to {} characters",
+            MAX_LABEL_LEN
+        );
+        assert!(
+            self.value.len() <= MAX_VALUE_LEN,
+            "Invalid artifact {}: longer than {} characters",
+            self.label,
+            MAX_VALUE_LEN
+        );
+
+        let valid = match self.kind {
+            ArtifactKind::SourceRepo
+            | ArtifactKind::DemoUrl
+            | ArtifactKind::Video
+            | ArtifactKind::PitchDeck => is_url(&self.value),
+            ArtifactKind::ContractAccount => self.value.parse::<AccountId>().is_ok(),
+            ArtifactKind::IpfsCid => is_ipfs_cid(&self.value),
+            ArtifactKind::Other => is_https_url(&self.value),
+        };
+        assert!(
+            valid,
+            "Invalid artifact {}: {:?} is not a valid {:?}",
+            self.label,
+            self.value,
+            self.kind
+        );
+    }
+}
+
+// Merge legacy `link` strings and typed artifacts into one validated list
+pub fn collect_artifacts(link: Vec<String>, artifacts: Vec<Artifact>) -> Vec<Artifact> {
+    let artifacts: Vec<Artifact> = link
+        .into_iter()
+        .map(Artifact::from_link)
+        .chain(artifacts)
+        .collect();
+
+    for artifact in artifacts.iter() {
+        artifact.assert_valid();
+    }
+
+    artifacts
+}
+
+// An http(s) URL with a host and no whitespace
+fn is_url(value: &str) -> bool {
+    url_host(value).is_some()
+}
+
+// An https URL, the scheme of links that fit none of the typed kinds
+fn is_https_url(value: &str) -> bool {
+    value.starts_with("https://") && is_url(value)
+}
+
+// The lowercased host of an http(s) URL, without user info and port
+fn url_host(value: &str) -> Option<String> {
+    let rest = value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))?;
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or("");
+    let host_port = authority.rsplit('@').next().unwrap_or("");
+    let host = host_port.split(':').next().unwrap_or("");
+
+    if host.is_empty() || value.chars().any(char::is_whitespace) {
+        None
+    } else {
+        Some(host.to_lowercase())
+    }
+}
+
+// The host is one of the domains or a subdomain of one, "notgithub.com" is not "github.com"
+fn is_on_domain(host: &str, domains: &[&str]) -> bool {
+    domains.iter().any(|domain| {
+        host == *domain || host.strip_suffix(domain).is_some_and(|sub| sub.ends_with('.'))
+    })
+}
+
+// A CIDv0 (base58 "Qm...") or a base32 CIDv1 ("b...")
+pub fn is_ipfs_cid(value: &str) -> bool {
+    if value.len() == 46 && value.starts_with("Qm") {
+        value.chars().all(|c| BASE58_ALPHABET.contains(c))
+    } else if value.len() >= 50 && value.starts_with('b') {
+        value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || ('2'..='7').contains(&c))
+    } else {
+        false
+    }
+}
\ No newline at end of file
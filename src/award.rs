@@ -1,8 +1,10 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::json_types::U128;
 use near_sdk::serde::{Serialize, Deserialize};
+use near_sdk::AccountId;
 
 use crate::submission::{SubmissionId, SubmissionJson};
+use crate::version::Versioned;
 
 pub type AwardId = u64;
 
@@ -13,23 +15,135 @@ pub struct Award {
     pub id: AwardId,
     pub name: String,
     pub price: U128,
+    pub funded: U128,
     pub winner: Option<SubmissionId>,
-    pub is_awarded: bool
+    pub is_awarded: bool,
+    pub payout: Option<AwardPayout>
 }
 
 // Implement the Award structure
 impl Award {
     pub fn new(id: AwardId,name: String, price: U128) -> Self {
-        Award { id: id, name, price, winner: None, is_awarded: false }
+        Award { id, name, price, funded: U128(0), winner: None, is_awarded: false, payout: None }
+    }
+
+    // Awards are priced in NEAR
+    pub fn token(&self) -> PrizeToken {
+        PrizeToken::Near
     }
 }
 
+// Define the payout of an award through a transfer proposal of the hackathon's DAO
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub enum AwardPayout {
+    // `add_proposal` was called and its callback has not run yet
+    Proposing { dao_id: AccountId, receiver_id: AccountId },
+    // `receiver_id` is the account the proposal transfers to
+    Proposed { dao_id: AccountId, proposal_id: u64, receiver_id: AccountId },
+}
+
+// Define the token a prize is paid in
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub enum PrizeToken {
+    Near,
+}
+
+// Define the PrizeTotals structure - the running prize totals of a hackathon in one token
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct PrizeTotals {
+    pub token: PrizeToken,
+    pub promised: U128,
+    pub funded: U128,
+    pub paid: U128
+}
+
+// Implement the PrizeTotals structure
+impl PrizeTotals {
+    pub fn new(token: PrizeToken) -> Self {
+        PrizeTotals { token, promised: U128(0), funded: U128(0), paid: U128(0) }
+    }
+}
+
+// Find the totals of a token, adding empty ones the first time the token is seen
+pub fn token_totals(prizes: &mut Vec<PrizeTotals>, token: PrizeToken) -> &mut PrizeTotals {
+    let index = match prizes.iter().position(|totals| totals.token == token) {
+        Some(index) => index,
+        None => {
+            prizes.push(PrizeTotals::new(token));
+            prizes.len() - 1
+        }
+    };
+    &mut prizes[index]
+}
+
+// Define the prize totals check structure (response) - the cached totals next to a recount
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct PrizeTotalsCheck {
+    pub cached: Vec<PrizeTotals>,
+    pub computed: Vec<PrizeTotals>,
+    pub consistent: bool
+}
+
 #[derive(BorshDeserialize, BorshSerialize,Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
 #[serde(crate = "near_sdk::serde")]
 pub struct AwardJson {
     pub id: AwardId,
     pub name: String,
     pub price: U128,
+    pub funded: U128,
     pub winner: Option<SubmissionJson>,
+    pub is_awarded: bool,
+    pub payout: Option<AwardPayout>
+}
+
+// Define the award layout before state versioning
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct AwardV0 {
+    pub id: AwardId,
+    pub name: String,
+    pub price: U128,
+    pub winner: Option<SubmissionId>,
     pub is_awarded: bool
+}
+
+// Define the versioned award, as it is stored
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum VersionedAward {
+    V0(AwardV0),
+    V1(Award),
+}
+
+// Implement the versioned award - older awards were paid in full or not funded at all
+impl Versioned for VersionedAward {
+    type Entity = Award;
+    type Legacy = AwardV0;
+    const CURRENT: u8 = 1;
+
+    fn from_legacy(legacy: AwardV0) -> Self {
+        VersionedAward::V0(legacy)
+    }
+
+    fn into_current(self) -> Award {
+        match self {
+            VersionedAward::V0(award) => Award {
+                id: award.id,
+                name: award.name,
+                funded: if award.is_awarded { award.price } else { U128(0) },
+                price: award.price,
+                winner: award.winner,
+                is_awarded: award.is_awarded,
+                payout: None,
+            },
+            VersionedAward::V1(award) => award,
+        }
+    }
 }
\ No newline at end of file
@@ -2,11 +2,13 @@ use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::serde::{Serialize, Deserialize};
 
 use crate::award::{AwardId, AwardJson};
+use crate::version::Versioned;
 
 pub type CategoryId = u64;
 
 // Define the Category structure
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
 #[serde(crate = "near_sdk::serde")]
 pub struct Category {
     pub id: CategoryId,
@@ -29,9 +31,33 @@ impl Category {
 
 // Define the Category Json structure (response)
 #[derive(BorshDeserialize, BorshSerialize,Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
 #[serde(crate = "near_sdk::serde")]
 pub struct CategoryJson {
     pub id: CategoryId,
     pub name: String,
     pub awards: Vec<AwardJson>
+}
+
+// Define the versioned category, as it is stored - the layout has not changed since the first version
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum VersionedCategory {
+    V0(Category),
+}
+
+// Implement the versioned category
+impl Versioned for VersionedCategory {
+    type Entity = Category;
+    type Legacy = Category;
+    const CURRENT: u8 = 0;
+
+    fn from_legacy(legacy: Category) -> Self {
+        VersionedCategory::V0(legacy)
+    }
+
+    fn into_current(self) -> Category {
+        match self {
+            VersionedCategory::V0(category) => category,
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,73 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, Timestamp};
+
+use crate::artifact::is_ipfs_cid;
+
+// Define the ContentRef structure - off-chain content (ipfs:// or ar://) pinned by its sha256
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct ContentRef {
+    pub uri: String,
+    pub sha256: String,
+}
+
+// Implement the ContentRef structure
+impl ContentRef {
+    pub fn assert_valid(&self) {
+        let valid_uri = if let Some(cid) = self.uri.strip_prefix("ipfs://") {
+            is_ipfs_cid(cid)
+        } else if let Some(tx) = self.uri.strip_prefix("ar://") {
+            tx.len() == 43
+                && tx
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        } else {
+            false
+        };
+        assert!(
+            valid_uri,
+            "Invalid content uri {:?}: expected ipfs://<cid> or ar://<transaction>",
+            self.uri
+        );
+        assert!(
+            is_lower_hex(&self.sha256, 64),
+            "Invalid content sha256 {:?}: expected 64 lowercase hex characters",
+            self.sha256
+        );
+    }
+}
+
+// Define the CommitAnchor structure - a git commit a team points judges at, with the time it was anchored
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct CommitAnchor {
+    pub hash: String,
+    pub time: Timestamp,
+}
+
+// Implement the CommitAnchor structure
+impl CommitAnchor {
+    pub fn new(hash: String) -> Self {
+        // SHA-1 object names, or SHA-256 for repositories using the newer object format
+        assert!(
+            is_lower_hex(&hash, 40) || is_lower_hex(&hash, 64),
+            "Invalid commit hash {:?}: expected 40 or 64 lowercase hex characters",
+            hash
+        );
+
+        CommitAnchor {
+            hash,
+            time: env::block_timestamp(),
+        }
+    }
+}
+
+fn is_lower_hex(value: &str, len: usize) -> bool {
+    value.len() == len
+        && value
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{ext_contract, AccountId, Gas};
+
+// Gas of the calls on the DAO and of the callbacks reading their results
+pub const GAS_FOR_DAO_CALL: Gas = Gas(30_000_000_000_000);
+pub const GAS_FOR_DAO_CALLBACK: Gas = Gas(15_000_000_000_000);
+
+// Define the proposal input of a SputnikDAO v2 - only the kinds this contract raises
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProposalInput {
+    pub description: String,
+    pub kind: ProposalKind,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub enum ProposalKind {
+    // An empty `token_id` transfers NEAR
+    Transfer {
+        token_id: String,
+        receiver_id: AccountId,
+        amount: U128,
+        msg: Option<String>,
+    },
+}
+
+// Define the part of a SputnikDAO proposal read back by `check_award_payout`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct Proposal {
+    pub status: ProposalStatus,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub enum ProposalStatus {
+    InProgress,
+    Approved,
+    Rejected,
+    Removed,
+    Expired,
+    Moved,
+    Failed,
+}
+
+// Define the SputnikDAO methods called by the contract, through the generated `ext_dao`
+#[ext_contract(ext_dao)]
+#[allow(dead_code)]
+pub trait SputnikDao {
+    fn add_proposal(&mut self, proposal: ProposalInput) -> u64;
+    fn get_proposal(&self, id: u64) -> Proposal;
+}
\ No newline at end of file
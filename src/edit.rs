@@ -0,0 +1,43 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, AccountId, Timestamp};
+
+use crate::award::AwardId;
+use crate::category::CategoryId;
+
+// Define what an edit was applied to
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub enum EditTarget {
+    Hackathon,
+    Category(CategoryId),
+    Award(AwardId),
+}
+
+// Define the Edit structure - one changed field of a hackathon, category or award
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct Edit {
+    pub editor: AccountId,
+    pub time: Timestamp,
+    pub target: EditTarget,
+    pub field: String,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+// Implement the Edit structure
+impl Edit {
+    pub fn new(target: EditTarget, field: &str, old_value: String, new_value: String) -> Self {
+        Edit {
+            editor: env::predecessor_account_id(),
+            time: env::block_timestamp(),
+            target,
+            field: field.to_string(),
+            old_value,
+            new_value,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,219 @@
+use near_sdk::json_types::U128;
+use near_sdk::serde::Serialize;
+use near_sdk::serde_json;
+use near_sdk::{env, AccountId, Timestamp};
+
+use crate::award::{AwardId, PrizeToken};
+use crate::category::CategoryId;
+use crate::dao::ProposalStatus;
+use crate::hackathon::HackathonId;
+use crate::submission::SubmissionId;
+use crate::team::TeamId;
+
+pub const EVENT_STANDARD: &str = "hackathon";
+pub const EVENT_VERSION: &str = "1.0.0";
+
+// Define the events logged on state changes, following NEP-297
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "event", content = "data", rename_all = "snake_case")]
+pub enum HackathonEvent {
+    MemberRegistered {
+        account_id: AccountId,
+    },
+    HackathonCreated {
+        hackathon_id: HackathonId,
+        owner_id: AccountId,
+        start: Timestamp,
+        end: Timestamp,
+    },
+    CategoryAdded {
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+    },
+    AwardAdded {
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        token: PrizeToken,
+        amount: U128,
+    },
+    ParticipantJoined {
+        hackathon_id: HackathonId,
+        account_id: AccountId,
+    },
+    ProjectSubmitted {
+        hackathon_id: HackathonId,
+        submission_id: SubmissionId,
+        team_id: TeamId,
+        account_ids: Vec<AccountId>,
+        category_ids: Vec<CategoryId>,
+    },
+    WinnerJudged {
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        submission_id: SubmissionId,
+    },
+    AwardPaid {
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        submission_id: SubmissionId,
+        receiver_id: AccountId,
+        token: PrizeToken,
+        amount: U128,
+    },
+    AwardPayoutProposed {
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        dao_id: AccountId,
+        proposal_id: u64,
+    },
+    // The proposal can no longer pay, the award may be proposed again
+    AwardPayoutClosed {
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        proposal_id: u64,
+        status: ProposalStatus,
+    },
+    HackathonUpdated {
+        hackathon_id: HackathonId,
+        start: Timestamp,
+        end: Timestamp,
+    },
+    HackathonCancelled {
+        hackathon_id: HackathonId,
+        refund: U128,
+    },
+    CategoryUpdated {
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+    },
+    AwardUpdated {
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        token: PrizeToken,
+        amount: U128,
+    },
+    ParticipantLeft {
+        hackathon_id: HackathonId,
+        account_id: AccountId,
+    },
+    // `award_ids` lists the awards the submission had won, they have no winner anymore
+    SubmissionWithdrawn {
+        hackathon_id: HackathonId,
+        submission_id: SubmissionId,
+        team_id: TeamId,
+        award_ids: Vec<AwardId>,
+    },
+    TeamCreated {
+        hackathon_id: HackathonId,
+        team_id: TeamId,
+        account_id: AccountId,
+    },
+    // `submission_ids` lists the submissions of the team whose members changed with it
+    TeamMemberJoined {
+        hackathon_id: HackathonId,
+        team_id: TeamId,
+        account_id: AccountId,
+        submission_ids: Vec<SubmissionId>,
+    },
+    TeamMemberLeft {
+        hackathon_id: HackathonId,
+        team_id: TeamId,
+        account_id: AccountId,
+        submission_ids: Vec<SubmissionId>,
+    },
+    // `funded` is the escrow of the award after the deposit
+    AwardFunded {
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        token: PrizeToken,
+        amount: U128,
+        funded: U128,
+    },
+    // `owner_id` is `None` when a pending proposal is cancelled
+    OwnerProposed {
+        hackathon_id: HackathonId,
+        owner_id: Option<AccountId>,
+    },
+    // The new owner is no longer an organizer
+    OwnerAccepted {
+        hackathon_id: HackathonId,
+        old_owner_id: AccountId,
+        owner_id: AccountId,
+    },
+    OrganizerAdded {
+        hackathon_id: HackathonId,
+        account_id: AccountId,
+    },
+    OrganizerRemoved {
+        hackathon_id: HackathonId,
+        account_id: AccountId,
+    },
+    PayoutDaoSet {
+        hackathon_id: HackathonId,
+        dao_id: Option<AccountId>,
+    },
+    SubmissionUpdated {
+        hackathon_id: HackathonId,
+        submission_id: SubmissionId,
+        revision: u32,
+    },
+    CommitAnchored {
+        hackathon_id: HackathonId,
+        submission_id: SubmissionId,
+        revision: u32,
+        commit_hash: String,
+    },
+    TeamMemberInvited {
+        hackathon_id: HackathonId,
+        team_id: TeamId,
+        account_id: AccountId,
+    },
+    TeamInvitationDeclined {
+        hackathon_id: HackathonId,
+        team_id: TeamId,
+        account_id: AccountId,
+    },
+    // `team_id` is set on the posts of a team looking for members
+    PostAdded {
+        hackathon_id: HackathonId,
+        author: AccountId,
+        team_id: Option<TeamId>,
+    },
+    PostRemoved {
+        hackathon_id: HackathonId,
+        author: AccountId,
+        team_id: Option<TeamId>,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+struct EventLog<'a> {
+    standard: &'static str,
+    version: &'static str,
+    #[serde(flatten)]
+    event: &'a HackathonEvent,
+}
+
+// Implement the HackathonEvent enum
+impl HackathonEvent {
+    pub fn emit(&self) {
+        let log = EventLog {
+            standard: EVENT_STANDARD,
+            version: EVENT_VERSION,
+            event: self,
+        };
+        env::log_str(&format!(
+            "EVENT_JSON:{}",
+            serde_json::to_string(&log).unwrap()
+        ));
+    }
+}
\ No newline at end of file
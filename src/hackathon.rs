@@ -0,0 +1,470 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::UnorderedSet;
+use near_sdk::json_types::U128;
+use near_sdk::{ AccountId, Timestamp, env, serde::{Serialize, Deserialize}};
+
+use crate::award::{token_totals, PrizeToken, PrizeTotals};
+use crate::category::{CategoryId, CategoryJson};
+use crate::content::ContentRef;
+use crate::member::MemberJson;
+use crate::submission::{SubmissionId, SubmissionJson};
+use crate::team::TeamId;
+use crate::version::Versioned;
+use crate::StorageKey;
+
+pub type HackathonId = u64;
+
+// Define the hackathon phase, derived from the block time and the hackathon dates
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub enum HackathonPhase {
+    Upcoming,
+    Building,
+    Ended,
+    Cancelled,
+}
+
+// Define the order of the calendar views
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub enum HackathonSort {
+    #[default]
+    Start,
+    Prize,
+}
+
+// Define the calendar cursor - the index key of the last hackathon on a calendar page, by start
+// time or by total prize depending on the order
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub enum CalendarCursor {
+    Start(Timestamp, HackathonId),
+    Prize(U128, HackathonId),
+}
+
+// Implement the calendar cursor
+impl CalendarCursor {
+    // Key in the start index, the cursor must come from a page in start order
+    pub fn start_key(self) -> (Timestamp, HackathonId) {
+        match self {
+            CalendarCursor::Start(start, hackathon_id) => (start, hackathon_id),
+            CalendarCursor::Prize(..) => env::panic_str("Cursor is not in start order"),
+        }
+    }
+
+    // Key in the prize index, the cursor must come from a page in prize order
+    pub fn prize_key(self) -> (u128, HackathonId) {
+        match self {
+            CalendarCursor::Prize(prize, hackathon_id) => (u128::MAX - prize.0, hackathon_id),
+            CalendarCursor::Start(..) => env::panic_str("Cursor is not in prize order"),
+        }
+    }
+}
+
+// Define the hackathon structure - participants, submissions and categories live in their own
+// per-hackathon collections, so joining does not rewrite every account that joined before
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct Hackathon {
+    pub owner: AccountId,
+    pub pending_owner: Option<AccountId>,
+    pub organizers: Vec<AccountId>,
+    pub id: HackathonId,
+    pub name: String,
+    pub description: String,
+    pub image: String,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    pub tags: Vec<String>,
+    pub content: Option<ContentRef>,
+    pub participants_list: UnorderedSet<AccountId>,
+    pub submissions_list: UnorderedSet<SubmissionId>,
+    pub categories_list: UnorderedSet<CategoryId>,
+    pub teams_list: Vec<TeamId>,
+    pub settings: HackathonSettings,
+    pub cancelled: bool,
+    // Running totals of the awards, one entry per token
+    pub prizes: Vec<PrizeTotals>,
+    // SputnikDAO that pays the winners through transfer proposals
+    pub payout_dao: Option<AccountId>
+}
+
+// Implement the hackathon structure
+impl Hackathon {
+    pub fn new(
+        id: HackathonId,
+        payload: HackathonPayload
+    ) -> Self {
+        Hackathon {
+            id,
+            owner: env::predecessor_account_id(),
+            pending_owner: None,
+            organizers: Vec::new(),
+            name: payload.name,
+            description: payload.description,
+            start: payload.start,
+            end: payload.end,
+            image: payload.image,
+            tags: payload.tags,
+            content: payload.content,
+            participants_list: UnorderedSet::new(StorageKey::HackathonParticipants { hackathon_id: id }),
+            submissions_list: UnorderedSet::new(StorageKey::HackathonSubmissions { hackathon_id: id }),
+            categories_list: UnorderedSet::new(StorageKey::HackathonCategories { hackathon_id: id }),
+            teams_list: Vec::new(),
+            settings: payload.settings,
+            cancelled: false,
+            prizes: Vec::new(),
+            payout_dao: payload.payout_dao
+        }
+    }
+
+    // The owner and the co-organizers may manage categories, awards and judging
+    pub fn is_organizer(&self, account_id: &AccountId) -> bool {
+        self.owner == *account_id || self.organizers.contains(account_id)
+    }
+
+    pub fn view(&self) -> HackathonView {
+        HackathonView {
+            owner: self.owner.clone(),
+            pending_owner: self.pending_owner.clone(),
+            organizers: self.organizers.clone(),
+            id: self.id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            image: self.image.clone(),
+            start: self.start,
+            end: self.end,
+            tags: self.tags.clone(),
+            content: self.content.clone(),
+            participants_list: self.participants_list.to_vec(),
+            submissions_list: self.submissions_list.to_vec(),
+            categories_list: self.categories_list.to_vec(),
+            teams_list: self.teams_list.clone(),
+            settings: self.settings.clone(),
+            cancelled: self.cancelled,
+            prizes: self.prizes.clone(),
+            payout_dao: self.payout_dao.clone(),
+        }
+    }
+
+    pub fn summary(&self) -> HackathonSummary {
+        HackathonSummary {
+            id: self.id,
+            owner: self.owner.clone(),
+            name: self.name.clone(),
+            image: self.image.clone(),
+            start: self.start,
+            end: self.end,
+            tags: self.tags.clone(),
+            phase: self.phase(),
+            participants_count: self.participants_list.len(),
+            submissions_count: self.submissions_list.len(),
+            categories_count: self.categories_list.len(),
+            teams_count: self.teams_list.len() as u64,
+            total_prize: self.total_prize(),
+            prizes: self.prizes.clone(),
+        }
+    }
+
+    // Promised NEAR prizes
+    pub fn total_prize(&self) -> U128 {
+        self.prizes
+            .iter()
+            .find(|totals| totals.token == PrizeToken::Near)
+            .map_or(U128(0), |totals| totals.promised)
+    }
+
+    // Key in the prize index, the prize is inverted so the largest comes first
+    pub fn prize_key(&self) -> (u128, HackathonId) {
+        (u128::MAX - self.total_prize().0, self.id)
+    }
+
+    pub fn prize_totals_mut(&mut self, token: PrizeToken) -> &mut PrizeTotals {
+        token_totals(&mut self.prizes, token)
+    }
+
+    pub fn phase(&self) -> HackathonPhase {
+        let now = env::block_timestamp();
+        if self.cancelled {
+            HackathonPhase::Cancelled
+        } else if now < self.start {
+            HackathonPhase::Upcoming
+        } else if now < self.end {
+            HackathonPhase::Building
+        } else {
+            HackathonPhase::Ended
+        }
+    }
+
+    // Cancelled hackathons are closed for good, like ended ones
+    pub fn assert_not_closed(&self) {
+        let phase = self.phase();
+        assert_ne!(phase, HackathonPhase::Ended, "Hackathon ended");
+        assert_ne!(phase, HackathonPhase::Cancelled, "Hackathon cancelled");
+    }
+}
+
+// Define the hackathon view structure (response) - the stored hackathon with its collections as lists
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct HackathonView {
+    pub owner: AccountId,
+    pub pending_owner: Option<AccountId>,
+    pub organizers: Vec<AccountId>,
+    pub id: HackathonId,
+    pub name: String,
+    pub description: String,
+    pub image: String,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    pub tags: Vec<String>,
+    pub content: Option<ContentRef>,
+    pub participants_list: Vec<AccountId>,
+    pub submissions_list: Vec<SubmissionId>,
+    pub categories_list: Vec<CategoryId>,
+    pub teams_list: Vec<TeamId>,
+    pub settings: HackathonSettings,
+    pub cancelled: bool,
+    pub prizes: Vec<PrizeTotals>,
+    pub payout_dao: Option<AccountId>
+}
+
+// Define the hackathon json structure (response)
+#[derive(BorshDeserialize, BorshSerialize,Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct HackathonJson {
+    pub participants: Vec<MemberJson>,
+    pub submissions: Vec<SubmissionJson>,
+    pub categories: Vec<CategoryJson>
+}
+
+// Define the hackathon payload structure (request)
+#[derive(BorshDeserialize, BorshSerialize,Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct HackathonPayload {
+    pub name: String,
+    pub description: String,
+    pub tags: Vec<String>,
+     pub image: String,
+     pub start: Timestamp,
+     pub end: Timestamp,
+     #[serde(default)]
+     pub settings: HackathonSettings,
+     #[serde(default)]
+     pub content: Option<ContentRef>,
+     #[serde(default)]
+     pub payout_dao: Option<AccountId>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize,Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct HackathonWithTotalPrize {
+    pub hackathon: HackathonView,
+    pub total_prize: U128, 
+}
+
+// Define the hackathon summary structure (response) - counts instead of the lists themselves
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct HackathonSummary {
+    pub id: HackathonId,
+    pub owner: AccountId,
+    pub name: String,
+    pub image: String,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    pub tags: Vec<String>,
+    pub phase: HackathonPhase,
+    pub participants_count: u64,
+    pub submissions_count: u64,
+    pub categories_count: u64,
+    pub teams_count: u64,
+    pub total_prize: U128,
+    pub prizes: Vec<PrizeTotals>,
+}
+
+// Define the hackathon update payload structure (request) - only the given fields are changed
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Default)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct HackathonUpdatePayload {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub image: Option<String>,
+    pub start: Option<Timestamp>,
+    pub end: Option<Timestamp>,
+    pub settings: Option<HackathonSettings>,
+    pub content: Option<ContentRef>,
+}
+
+// Define the hackathon settings structure - team and submission rules, `None` means unlimited
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct HackathonSettings {
+    pub min_team_size: Option<u32>,
+    pub max_team_size: Option<u32>,
+    pub max_submissions_per_team: Option<u32>,
+    pub max_categories_per_submission: Option<u32>,
+}
+
+// Implement the hackathon settings structure - every check panics with the name of the violated rule
+impl HackathonSettings {
+    pub fn assert_valid(&self) {
+        if let Some(min) = self.min_team_size {
+            assert!(min >= 1, "Rule min_team_size must be at least 1");
+        }
+        if let (Some(min), Some(max)) = (self.min_team_size, self.max_team_size) {
+            assert!(min <= max, "Rule min_team_size {} is above max_team_size {}", min, max);
+        }
+        for (rule, value) in [
+            ("max_team_size", self.max_team_size),
+            ("max_submissions_per_team", self.max_submissions_per_team),
+            ("max_categories_per_submission", self.max_categories_per_submission),
+        ] {
+            if let Some(value) = value {
+                assert!(value >= 1, "Rule {} must be at least 1", rule);
+            }
+        }
+    }
+
+    pub fn assert_team_size(&self, size: usize) {
+        if let Some(min) = self.min_team_size {
+            assert!(
+                size >= min as usize,
+                "Rule min_team_size violated: team has {} members, at least {} required",
+                size,
+                min
+            );
+        }
+        if let Some(max) = self.max_team_size {
+            assert!(
+                size <= max as usize,
+                "Rule max_team_size violated: team has {} members, at most {} allowed",
+                size,
+                max
+            );
+        }
+    }
+
+    pub fn assert_team_has_room(&self, size: usize) {
+        if let Some(max) = self.max_team_size {
+            assert!(
+                size < max as usize,
+                "Rule max_team_size violated: team already has {} of {} members",
+                size,
+                max
+            );
+        }
+    }
+
+    pub fn assert_team_can_submit(&self, submissions: usize) {
+        if let Some(max) = self.max_submissions_per_team {
+            assert!(
+                submissions < max as usize,
+                "Rule max_submissions_per_team violated: team already made {} of {} submissions",
+                submissions,
+                max
+            );
+        }
+    }
+
+    pub fn assert_category_count(&self, categories: usize) {
+        if let Some(max) = self.max_categories_per_submission {
+            assert!(
+                categories <= max as usize,
+                "Rule max_categories_per_submission violated: {} categories given, at most {} allowed",
+                categories,
+                max
+            );
+        }
+    }
+}
+
+// Define the hackathon layout before state versioning, with inline lists and no teams
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct HackathonV0 {
+    pub owner: AccountId,
+    pub id: HackathonId,
+    pub name: String,
+    pub description: String,
+    pub image: String,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    pub tags: Vec<String>,
+    pub participants_list: Vec<AccountId>,
+    pub submissions_list: Vec<SubmissionId>,
+    pub categories_list: Vec<CategoryId>
+}
+
+// Implement the HackathonV0 structure
+impl HackathonV0 {
+    pub fn migrate(self, teams_list: Vec<TeamId>) -> Hackathon {
+        let mut hackathon = Hackathon {
+            owner: self.owner,
+            pending_owner: None,
+            organizers: Vec::new(),
+            id: self.id,
+            name: self.name,
+            description: self.description,
+            image: self.image,
+            start: self.start,
+            end: self.end,
+            tags: self.tags,
+            content: None,
+            participants_list: UnorderedSet::new(StorageKey::HackathonParticipants {
+                hackathon_id: self.id,
+            }),
+            submissions_list: UnorderedSet::new(StorageKey::HackathonSubmissions {
+                hackathon_id: self.id,
+            }),
+            categories_list: UnorderedSet::new(StorageKey::HackathonCategories {
+                hackathon_id: self.id,
+            }),
+            teams_list,
+            settings: HackathonSettings::default(),
+            cancelled: false,
+            prizes: Vec::new(),
+            payout_dao: None,
+        };
+        hackathon.participants_list.extend(self.participants_list);
+        hackathon.submissions_list.extend(self.submissions_list);
+        hackathon.categories_list.extend(self.categories_list);
+        hackathon
+    }
+}
+
+// Define the versioned hackathon, as it is stored - only decoded one at a time, so the size
+// difference between layouts does not matter
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum VersionedHackathon {
+    V0(HackathonV0),
+    V1(Hackathon),
+}
+
+// Implement the versioned hackathon - older hackathons get their collections and teams during `migrate`
+impl Versioned for VersionedHackathon {
+    type Entity = Hackathon;
+    type Legacy = HackathonV0;
+    const CURRENT: u8 = 1;
+
+    fn from_legacy(legacy: HackathonV0) -> Self {
+        VersionedHackathon::V0(legacy)
+    }
+
+    fn into_current(self) -> Hackathon {
+        match self {
+            VersionedHackathon::V0(_) => env::panic_str("Hackathon not migrated"),
+            VersionedHackathon::V1(hackathon) => hackathon,
+        }
+    }
+}
\ No newline at end of file
@@ -1,21 +1,99 @@
-use award::{Award, AwardId, AwardJson};
+// `#[near_bindgen]` re-emits every public method as a free function, so the lint has to be
+// silenced crate-wide for `submit_project`.
+#![allow(clippy::too_many_arguments)]
+
+use std::ops::Bound;
+
+use artifact::{collect_artifacts, Artifact};
+use award::{
+    token_totals, Award, AwardId, AwardJson, AwardPayout, PrizeTotals, PrizeTotalsCheck,
+    VersionedAward,
+};
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{LookupMap, UnorderedSet};
-use near_sdk::json_types::U128;
-use near_sdk::{env, near_bindgen, AccountId, Balance, PanicOnDefault, Promise, Timestamp};
+use near_sdk::collections::{LookupMap, TreeMap, UnorderedSet};
+use near_sdk::json_types::{Base58CryptoHash, U128, U64};
+use near_sdk::{
+    assert_one_yocto, env, near_bindgen, AccountId, Balance, BorshStorageKey, CryptoHash, Gas,
+    PanicOnDefault, Promise, PromiseError, StorageUsage, Timestamp,
+};
 
 // declare module
+#[cfg(all(feature = "abi", not(target_arch = "wasm32")))]
+pub mod abi;
+mod artifact;
 mod award;
 mod category;
+mod content;
+mod dao;
+mod edit;
+mod event;
 mod hackathon;
 mod member;
+mod page;
+mod post;
+mod storage;
 mod submission;
+mod team;
+pub mod types;
+mod upgrade;
+mod version;
+
+#[cfg(test)]
+mod tests;
 
 // import module
-use category::{Category, CategoryId, CategoryJson};
-use hackathon::{Hackathon, HackathonId, HackathonJson, HackathonPayload, HackathonWithTotalPrize};
-use member::{Member, MemberJson, MemberJsonDetail};
-use submission::{Submission, SubmissionId, SubmissionJson};
+use category::{Category, CategoryId, CategoryJson, VersionedCategory};
+use content::CommitAnchor;
+use dao::{
+    ext_dao, Proposal, ProposalInput, ProposalKind, ProposalStatus, GAS_FOR_DAO_CALL,
+    GAS_FOR_DAO_CALLBACK,
+};
+use edit::{Edit, EditTarget};
+use event::HackathonEvent;
+use hackathon::{
+    CalendarCursor, Hackathon, HackathonId, HackathonJson, HackathonPayload, HackathonPhase,
+    HackathonSort, HackathonSummary, HackathonUpdatePayload, HackathonWithTotalPrize,
+    VersionedHackathon,
+};
+use member::{Member, MemberJson, MemberJsonDetail, VersionedMember};
+use page::{cursor_page, page_range, page_slice, scan_page, CursorPage, Page};
+use post::{Post, PostMatch};
+use storage::{StorageAccount, StorageBalance, StorageBalanceBounds, MIN_STORAGE_BYTES};
+use submission::{
+    Submission, SubmissionId, SubmissionJson, SubmissionRevision, SubmissionUpdatePayload,
+    VersionedSubmission,
+};
+use team::{Team, TeamAction, TeamId, VersionedTeam};
+use upgrade::{StagedUpgrade, GAS_FOR_UPGRADE, GAS_FOR_UPGRADE_CALLBACK};
+use version::{
+    legacy_list_index, legacy_list_slot, ContractSourceMetadata, HackathonContractV0,
+    MigrationCursor, MigrationStage, Standard, StateVersion, VersionedMap, CONTRACT_VERSION,
+    DEFAULT_MIGRATION_LIMIT, STATE_VERSION,
+};
+
+// Define the storage prefix of every collection - nested collections carry the id of their entity
+#[derive(BorshSerialize, BorshStorageKey)]
+pub enum StorageKey {
+    MembersList,
+    HackathonsList,
+    Members,
+    Awards,
+    Hackathons,
+    Submissions,
+    Categories,
+    Edits,
+    Teams,
+    Posts,
+    SubmissionRevisions,
+    StorageAccounts,
+    HackathonParticipants { hackathon_id: HackathonId },
+    HackathonSubmissions { hackathon_id: HackathonId },
+    HackathonCategories { hackathon_id: HackathonId },
+    HackathonsByTag,
+    TagHackathons { tag_hash: CryptoHash },
+    HackathonsByStart,
+    HackathonsByPrize,
+}
 
 // Define the contract structure
 #[near_bindgen]
@@ -24,35 +102,75 @@ pub struct HackathonContract {
     pub members_list: UnorderedSet<AccountId>,
     pub hackathons_list: UnorderedSet<HackathonId>,
 
-    pub members: LookupMap<AccountId, Member>,
-    pub awards: LookupMap<AwardId, Award>,
-    pub hackathons: LookupMap<HackathonId, Hackathon>,
-    pub submissions: LookupMap<SubmissionId, Submission>,
-    pub categories: LookupMap<CategoryId, Category>,
+    pub members: VersionedMap<AccountId, VersionedMember>,
+    pub awards: VersionedMap<AwardId, VersionedAward>,
+    pub hackathons: VersionedMap<HackathonId, VersionedHackathon>,
+    pub submissions: VersionedMap<SubmissionId, VersionedSubmission>,
+    pub categories: VersionedMap<CategoryId, VersionedCategory>,
+    pub edits: LookupMap<HackathonId, Vec<Edit>>,
+    pub teams: VersionedMap<TeamId, VersionedTeam>,
+    pub posts: LookupMap<HackathonId, Vec<Post>>,
+    pub submission_revisions: LookupMap<(SubmissionId, u32), SubmissionRevision>,
+    pub storage_accounts: LookupMap<AccountId, StorageAccount>,
 
     pub next_submission_id: SubmissionId,
     pub next_category_id: CategoryId,
     pub next_award_id: AwardId,
     pub next_hackathon_id: HackathonId,
+    pub next_team_id: TeamId,
+
+    pub state_version: u32,
+    pub migration: Option<MigrationCursor>,
+
+    pub owner_id: AccountId,
+    pub pending_owner_id: Option<AccountId>,
+    pub upgrade_delay: Timestamp,
+    pub staged_upgrade: Option<StagedUpgrade>,
+
+    // Cancelled hackathons are left out of the tag, start time and prize indexes
+    pub hackathons_by_tag: LookupMap<String, UnorderedSet<HackathonId>>,
+    pub hackathons_by_start: TreeMap<(Timestamp, HackathonId), ()>,
+    pub hackathons_by_prize: TreeMap<(u128, HackathonId), ()>,
+
+    // Bytes the current call billed to the payers of the entities it wrote, not stored
+    #[borsh_skip]
+    billed_to_payers: i64,
 }
 
 // Implement the contract structure
 #[near_bindgen]
 impl HackathonContract {
+    // `upgrade_delay` is how long a staged upgrade waits before it can be deployed, none by default
     #[init]
-    pub fn init() -> Self {
+    pub fn init(owner_id: Option<AccountId>, upgrade_delay: Option<U64>) -> Self {
         Self {
-            members_list: UnorderedSet::new(b"m".to_vec()),
-            hackathons_list: UnorderedSet::new(b"m".to_vec()),
-            members: LookupMap::new(b"members".to_vec()),
-            hackathons: LookupMap::new(b"hackathons".to_vec()),
-            submissions: LookupMap::new(b"submissions".to_vec()),
-            categories: LookupMap::new(b"categories".to_vec()),
-            awards: LookupMap::new(b"awards".to_vec()),
+            members_list: UnorderedSet::new(StorageKey::MembersList),
+            hackathons_list: UnorderedSet::new(StorageKey::HackathonsList),
+            members: VersionedMap::new(StorageKey::Members),
+            hackathons: VersionedMap::new(StorageKey::Hackathons),
+            submissions: VersionedMap::new(StorageKey::Submissions),
+            categories: VersionedMap::new(StorageKey::Categories),
+            awards: VersionedMap::new(StorageKey::Awards),
+            edits: LookupMap::new(StorageKey::Edits),
+            teams: VersionedMap::new(StorageKey::Teams),
+            posts: LookupMap::new(StorageKey::Posts),
+            submission_revisions: LookupMap::new(StorageKey::SubmissionRevisions),
+            storage_accounts: LookupMap::new(StorageKey::StorageAccounts),
             next_hackathon_id: 0,
             next_submission_id: 0,
             next_category_id: 0,
             next_award_id: 0,
+            next_team_id: 0,
+            state_version: STATE_VERSION,
+            migration: None,
+            owner_id: owner_id.unwrap_or_else(env::predecessor_account_id),
+            pending_owner_id: None,
+            upgrade_delay: upgrade_delay.map_or(0, |delay| delay.0),
+            staged_upgrade: None,
+            hackathons_by_tag: LookupMap::new(StorageKey::HackathonsByTag),
+            hackathons_by_start: TreeMap::new(StorageKey::HackathonsByStart),
+            hackathons_by_prize: TreeMap::new(StorageKey::HackathonsByPrize),
+            billed_to_payers: 0,
         }
     }
 }
@@ -61,35 +179,56 @@ impl HackathonContract {
 #[near_bindgen]
 impl HackathonContract {
     pub fn add_member(&mut self, name: String, image: Option<String>, bio: Option<String>) {
-        let id = env::signer_account_id();
+        let initial_storage = env::storage_usage();
+
+        let id = env::predecessor_account_id();
+        assert!(!self.members.contains_key(&id), "Member exists");
+
         let member = Member::new(&id, name, image, bio);
 
         self.members_list.insert(&id);
         self.members.insert(&id, &member);
+
+        HackathonEvent::MemberRegistered { account_id: id }.emit();
+
+        self.charge_storage(initial_storage);
     }
 
     pub fn add_hackathon(&mut self, payload: HackathonPayload) -> Option<HackathonId> {
+        let initial_storage = env::storage_usage();
+
+        let creator_id = env::predecessor_account_id();
+        assert!(self.members.contains_key(&creator_id), "Not a member");
+
         let hackathon_id = self.next_hackathon_id;
         self.next_hackathon_id += 1;
 
-        let creator_id = env::signer_account_id();
-        assert_eq!(
-            creator_id,
-            self.members.get(&creator_id).unwrap().id,
-            "Not a member"
-        );
-
         match self.members.get(&creator_id) {
             Some(mut creator) => {
                 creator.created_hackathons.push(hackathon_id);
 
                 let hackathon = Hackathon::new(hackathon_id, payload);
+                assert!(hackathon.start < hackathon.end, "Start must be before end");
+                hackathon.settings.assert_valid();
+                if let Some(content) = &hackathon.content {
+                    content.assert_valid();
+                }
 
                 self.hackathons_list.insert(&hackathon_id);
+                self.index_hackathon(&hackathon);
                 self.hackathons.insert(&hackathon_id, &hackathon);
 
                 self.members.insert(&creator_id, &creator);
 
+                HackathonEvent::HackathonCreated {
+                    hackathon_id,
+                    owner_id: creator_id,
+                    start: hackathon.start,
+                    end: hackathon.end,
+                }
+                .emit();
+                self.charge_storage(initial_storage);
+
                 Some(hackathon_id)
             }
             None => None,
@@ -97,10 +236,13 @@ impl HackathonContract {
     }
 
     pub fn add_category(&mut self, hackathon_id: HackathonId, name: String) -> Option<u64> {
+        let initial_storage = env::storage_usage();
+
         if let Some(mut hackathon) = self.hackathons.get(&hackathon_id) {
-            let account_id = env::signer_account_id();
+            let account_id = env::predecessor_account_id();
 
-            assert_eq!(account_id, hackathon.owner, "Not owner");
+            assert!(hackathon.is_organizer(&account_id), "Not organizer");
+            assert!(!hackathon.cancelled, "Hackathon cancelled");
 
             let category_id = self.next_category_id;
             self.next_category_id += 1;
@@ -109,9 +251,16 @@ impl HackathonContract {
 
             self.categories.insert(&category_id, &category);
 
-            hackathon.categories_list.push(category_id);
+            hackathon.categories_list.insert(&category_id);
             self.hackathons.insert(&hackathon_id, &hackathon);
 
+            HackathonEvent::CategoryAdded {
+                hackathon_id,
+                category_id,
+            }
+            .emit();
+            self.charge_storage(initial_storage);
+
             Some(category_id)
         } else {
             None
@@ -125,462 +274,3099 @@ impl HackathonContract {
         name: String,
         total: f64,
     ) -> Option<AwardId> {
-        assert_eq!(
-            env::signer_account_id(),
-            self.hackathons.get(&hackathon_id).unwrap().owner,
-            "Not owner"
+        let initial_storage = env::storage_usage();
+
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert!(
+            hackathon.is_organizer(&env::predecessor_account_id()),
+            "Not organizer"
+        );
+        assert!(!hackathon.cancelled, "Hackathon cancelled");
+        assert!(
+            hackathon.categories_list.contains(&category_id),
+            "category is not in hackathon"
         );
 
         if let Some(mut category) = self.categories.get(&category_id) {
             let award_id = self.next_award_id;
             self.next_award_id += 1;
 
-            let award = Award::new(award_id, name, U128((total * 1_000_000_000_000_000_000_000_000.0).trunc() as u128));
+            let award = Award::new(award_id, name, U128(near_to_yocto(total)));
 
             self.awards.insert(&award_id, &award);
 
             category.awards.push(award_id);
             self.categories.insert(&category_id, &category);
 
+            let prize_key = hackathon.prize_key();
+            hackathon.prize_totals_mut(award.token()).promised.0 += award.price.0;
+            self.hackathons.insert(&hackathon_id, &hackathon);
+            self.reindex_prize(&hackathon, prize_key);
+
+            HackathonEvent::AwardAdded {
+                hackathon_id,
+                category_id,
+                award_id,
+                token: award.token(),
+                amount: award.price,
+            }
+            .emit();
+            self.charge_storage(initial_storage);
+
             Some(award_id)
         } else {
-            return None;
+            None
         }
     }
 
-    pub fn join_hackathon(&mut self, hackathon_id: u64) {
-        let account_id = env::signer_account_id();
-        if let Some(mut member) = self.members.get(&account_id) {
-            assert_eq!(
-                account_id,
-                self.members.get(&account_id).unwrap().id,
-                "Not a member"
-            );
+    pub fn update_hackathon(&mut self, hackathon_id: HackathonId, payload: HackathonUpdatePayload) {
+        let initial_storage = env::storage_usage();
+
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert_eq!(env::predecessor_account_id(), hackathon.owner, "Not owner");
+        assert!(!hackathon.cancelled, "Hackathon cancelled");
+
+        let phase = hackathon.phase();
+        let start = payload.start.unwrap_or(hackathon.start);
+        let end = payload.end.unwrap_or(hackathon.end);
+        assert!(start < end, "Start must be before end");
+
+        let mut edits = Vec::new();
+
+        if let Some(name) = payload.name {
+            edits.push(Edit::new(
+                EditTarget::Hackathon,
+                "name",
+                hackathon.name,
+                name.clone(),
+            ));
+            hackathon.name = name;
+        }
+        if let Some(description) = payload.description {
+            edits.push(Edit::new(
+                EditTarget::Hackathon,
+                "description",
+                hackathon.description,
+                description.clone(),
+            ));
+            hackathon.description = description;
+        }
+        if let Some(image) = payload.image {
+            edits.push(Edit::new(
+                EditTarget::Hackathon,
+                "image",
+                hackathon.image,
+                image.clone(),
+            ));
+            hackathon.image = image;
+        }
+        if let Some(tags) = payload.tags {
+            edits.push(Edit::new(
+                EditTarget::Hackathon,
+                "tags",
+                hackathon.tags.join(","),
+                tags.join(","),
+            ));
+            self.unindex_tags(hackathon_id, &hackathon.tags);
+            self.index_tags(hackathon_id, &tags);
+            hackathon.tags = tags;
+        }
+        if start != hackathon.start {
+            assert_eq!(phase, HackathonPhase::Upcoming, "Hackathon already started");
+            edits.push(Edit::new(
+                EditTarget::Hackathon,
+                "start",
+                hackathon.start.to_string(),
+                start.to_string(),
+            ));
+            self.hackathons_by_start
+                .remove(&(hackathon.start, hackathon_id));
+            self.hackathons_by_start.insert(&(start, hackathon_id), &());
+            hackathon.start = start;
+        }
+        if end != hackathon.end {
+            match phase {
+                HackathonPhase::Upcoming => (),
+                HackathonPhase::Building => {
+                    assert!(end > env::block_timestamp(), "End must be in the future")
+                }
+                HackathonPhase::Ended | HackathonPhase::Cancelled => {
+                    assert!(end > hackathon.end, "Can't shorten a passed deadline")
+                }
+            }
+            edits.push(Edit::new(
+                EditTarget::Hackathon,
+                "end",
+                hackathon.end.to_string(),
+                end.to_string(),
+            ));
+            hackathon.end = end;
+        }
 
-            if let Some(mut hackathon) = self.hackathons.get(&hackathon_id) {
-                assert_ne!(account_id, hackathon.owner, "Can't join your own");
-                assert_eq!(
-                    hackathon.participants_list.contains(&account_id),
-                    false,
-                    "Existing in hackathon"
-                );
+        if let Some(settings) = payload.settings {
+            assert_ne!(phase, HackathonPhase::Ended, "Hackathon ended");
+            settings.assert_valid();
+            edits.push(Edit::new(
+                EditTarget::Hackathon,
+                "settings",
+                near_sdk::serde_json::to_string(&hackathon.settings).unwrap(),
+                near_sdk::serde_json::to_string(&settings).unwrap(),
+            ));
+            hackathon.settings = settings;
+        }
+        if let Some(content) = payload.content {
+            content.assert_valid();
+            edits.push(Edit::new(
+                EditTarget::Hackathon,
+                "content",
+                near_sdk::serde_json::to_string(&hackathon.content).unwrap(),
+                near_sdk::serde_json::to_string(&content).unwrap(),
+            ));
+            hackathon.content = Some(content);
+        }
 
-                hackathon.participants_list.push(account_id.clone());
-                self.hackathons.insert(&hackathon_id, &hackathon);
-            }
+        self.hackathons.insert(&hackathon_id, &hackathon);
+        self.record_edits(hackathon_id, edits);
 
-            member.joined_hackathons.push(hackathon_id);
-            self.members.insert(&account_id, &member);
+        HackathonEvent::HackathonUpdated {
+            hackathon_id,
+            start: hackathon.start,
+            end: hackathon.end,
         }
+        .emit();
+        self.charge_storage(initial_storage);
     }
 
-    pub fn submit_project(
+    pub fn update_category(
         &mut self,
         hackathon_id: HackathonId,
-        categories: Vec<CategoryId>,
-        members: Vec<AccountId>,
+        category_id: CategoryId,
         name: String,
-        description: String,
-        image: String,
-        link: Vec<String>,
-        time: Timestamp,
     ) {
-        if let Some(mut hackathon) = self.hackathons.get(&hackathon_id) {
-            for member in members.iter() {
-                assert_eq!(
-                    self.members.contains_key(member),
-                    true,
-                    "Participant not a member"
-                );
-                assert_eq!(
-                    hackathon.participants_list.contains(&member),
-                    true,
-                    "Not participated in hackathon"
-                );
-            }
+        let initial_storage = env::storage_usage();
+
+        let hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert!(
+            hackathon.is_organizer(&env::predecessor_account_id()),
+            "Not organizer"
+        );
+        assert!(!hackathon.cancelled, "Hackathon cancelled");
+        assert!(
+            hackathon.categories_list.contains(&category_id),
+            "category is not in hackathon"
+        );
 
-            let submission_id: SubmissionId = self.next_submission_id;
-            let submission = Submission::new(
-                submission_id,
-                categories,
-                members,
-                name,
-                description,
-                image,
-                link,
-                time,
-            );
-            self.submissions.insert(&submission_id, &submission);
+        let mut category = self
+            .categories
+            .get(&category_id)
+            .expect("Category not exist");
+        let edit = Edit::new(
+            EditTarget::Category(category_id),
+            "name",
+            category.name,
+            name.clone(),
+        );
+        category.name = name;
 
-            hackathon.submissions_list.push(submission_id);
-            self.hackathons.insert(&hackathon_id, &hackathon);
+        self.categories.insert(&category_id, &category);
+        self.record_edits(hackathon_id, vec![edit]);
 
-            self.next_submission_id += 1;
+        HackathonEvent::CategoryUpdated {
+            hackathon_id,
+            category_id,
         }
+        .emit();
+        self.charge_storage(initial_storage);
     }
 
-    pub fn judge_winner(
+    pub fn update_award(
         &mut self,
         hackathon_id: HackathonId,
         category_id: CategoryId,
         award_id: AwardId,
-        submission_id: SubmissionId,
+        name: Option<String>,
+        total: Option<f64>,
     ) {
-        let account_id = env::signer_account_id();
-
-        if let Some(hackathon) = self.hackathons.get(&hackathon_id) {
-            let winners = self.submissions.get(&submission_id).unwrap().members;
-
-            for winner in winners.iter() {
-                assert_eq!(
-                    *winner,
-                    self.members.get(&winner).unwrap().id,
-                    "Winner is not a member"
-                );
-                assert_eq!(
-                    hackathon.participants_list.contains(&winner),
-                    true,
-                    "Not participated"
-                );
-            }
-            assert_eq!(account_id, hackathon.owner, "Not nowner");
-            assert_eq!(
-                hackathon.categories_list.contains(&category_id),
-                true,
-                "category is not in hackathon"
-            );
-
-            if let Some(category) = self.categories.get(&category_id) {
+        let initial_storage = env::storage_usage();
+
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert!(
+            hackathon.is_organizer(&env::predecessor_account_id()),
+            "Not organizer"
+        );
+        assert!(!hackathon.cancelled, "Hackathon cancelled");
+        let mut award = self.award_in_hackathon(&hackathon, category_id, award_id);
+        assert!(!award.is_awarded, "award awarded");
+        assert!(award.payout.is_none(), "Payout proposed to the DAO");
+
+        let mut edits = Vec::new();
+
+        if let Some(name) = name {
+            edits.push(Edit::new(
+                EditTarget::Award(award_id),
+                "name",
+                award.name,
+                name.clone(),
+            ));
+            award.name = name;
+        }
+        if let Some(total) = total {
+            let price = U128(near_to_yocto(total));
+            if price.0 < award.price.0 {
+                assert_eq!(award.funded.0, 0, "Can't lower a funded prize");
                 assert_eq!(
-                    category.awards.contains(&award_id),
-                    true,
-                    "award is not in category"
+                    hackathon.phase(),
+                    HackathonPhase::Upcoming,
+                    "Can't lower a prize after the hackathon started"
                 );
             }
+            edits.push(Edit::new(
+                EditTarget::Award(award_id),
+                "price",
+                award.price.0.to_string(),
+                price.0.to_string(),
+            ));
+            let prize_key = hackathon.prize_key();
+            let totals = hackathon.prize_totals_mut(award.token());
+            totals.promised = U128(totals.promised.0 - award.price.0 + price.0);
+            award.price = price;
+            self.reindex_prize(&hackathon, prize_key);
+        }
 
-            if let Some(mut award) = self.awards.get(&award_id) {
-                assert_eq!(award.winner, None, "Winner judged");
-
-                award.winner = Some(submission_id);
+        self.awards.insert(&award_id, &award);
+        self.hackathons.insert(&hackathon_id, &hackathon);
+        self.record_edits(hackathon_id, edits);
 
-                self.awards.insert(&award_id, &award);
-            }
+        HackathonEvent::AwardUpdated {
+            hackathon_id,
+            category_id,
+            award_id,
+            token: award.token(),
+            amount: award.price,
         }
+        .emit();
+        self.charge_storage(initial_storage);
     }
 
-    pub fn update_member(
-        &mut self,
-        name: Option<String>,
-        image: Option<String>,
-        bio: Option<String>,
-    ) -> Option<MemberJson> {
-        let id: AccountId = env::signer_account_id();
-        assert_eq!(self.members_list.contains(&id), true, "Member not exist");
-        match self.members.get(&id) {
-            Some(mut mem) => {
-                match name {
-                    Some(name) => mem.name = name.clone(),
-                    None => (),
-                };
-                match image {
-                    Some(image) => mem.image = Some(image.clone()),
-                    None => (),
-                };
-                match bio {
-                    Some(bio) => mem.bio = Some(bio.clone()),
-                    None => (),
-                };
-
-                self.members.insert(&id, &mem);
+    pub fn add_organizer(&mut self, hackathon_id: HackathonId, account_id: AccountId) {
+        let initial_storage = env::storage_usage();
+
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert_eq!(env::predecessor_account_id(), hackathon.owner, "Not owner");
+        assert!(!hackathon.cancelled, "Hackathon cancelled");
+        assert!(self.members.contains_key(&account_id), "Not a member");
+        assert!(!hackathon.is_organizer(&account_id), "Already organizer");
+        assert!(
+            !hackathon.participants_list.contains(&account_id),
+            "Existing in hackathon"
+        );
 
-                Some(MemberJson {
-                    id,
-                    name: mem.name,
-                    image: mem.image,
-                    bio: mem.bio,
-                })
-            }
-            None => None,
-        }
-    }
-}
+        hackathon.organizers.push(account_id.clone());
+        self.hackathons.insert(&hackathon_id, &hackathon);
 
-// PAYABLE FUNCTION
-#[near_bindgen]
-impl HackathonContract {
-    #[payable]
-    pub fn award_winner(
-        &mut self,
-        hackathon_id: HackathonId,
-        category_id: CategoryId,
-        award_id: AwardId,
-    ) {
-        let account_id = env::signer_account_id();
+        let edit = Edit::new(
+            EditTarget::Hackathon,
+            "organizers",
+            String::new(),
+            account_id.to_string(),
+        );
+        self.record_edits(hackathon_id, vec![edit]);
 
-        if let Some(hackathon) = self.hackathons.get(&hackathon_id) {
-            assert_eq!(account_id, hackathon.owner, "Not nowner");
-            assert_eq!(
-                hackathon.categories_list.contains(&category_id),
-                true,
-                "category is not in hackathon"
-            );
+        HackathonEvent::OrganizerAdded {
+            hackathon_id,
+            account_id,
         }
+        .emit();
+        self.charge_storage(initial_storage);
+    }
 
-        if let Some(category) = self.categories.get(&category_id) {
-            assert_eq!(
-                category.awards.contains(&award_id),
-                true,
-                "award is not in category"
-            );
-        }
+    pub fn remove_organizer(&mut self, hackathon_id: HackathonId, account_id: AccountId) {
+        let initial_storage = env::storage_usage();
 
-        if let Some(mut award) = self.awards.get(&award_id) {
-            assert_eq!(award.is_awarded, false, "award awarded");
-            assert_eq!(
-                U128(env::attached_deposit()),
-                award.price,
-                "attached deposit should be equal to the award"
-            );
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert_eq!(env::predecessor_account_id(), hackathon.owner, "Not owner");
+        assert!(hackathon.organizers.contains(&account_id), "Not organizer");
 
-            let receiver = self
-                .submissions
-                .get(&award.winner.clone().unwrap())
-                .and_then(|sub| sub.members.first().cloned())
-                .unwrap();
+        hackathon.organizers.retain(|o| *o != account_id);
+        self.hackathons.insert(&hackathon_id, &hackathon);
 
-            Promise::new(receiver).transfer(award.price.into());
-            award.is_awarded = true;
+        let edit = Edit::new(
+            EditTarget::Hackathon,
+            "organizers",
+            account_id.to_string(),
+            String::new(),
+        );
+        self.record_edits(hackathon_id, vec![edit]);
 
-            self.awards.insert(&award_id, &award);
+        HackathonEvent::OrganizerRemoved {
+            hackathon_id,
+            account_id,
         }
+        .emit();
+        self.charge_storage(initial_storage);
     }
-}
 
-// PUBLIC VIEW FUNCTION
-#[near_bindgen]
-impl HackathonContract {
-    pub fn get_all_hackathons(&self) -> Vec<Option<HackathonWithTotalPrize>> {
-        let mut hackathons_with_prizes = Vec::new();
-
-        for hackathon in self.hackathons_list.iter() {
-            match self.get_hackathon_by_id(hackathon) {
-                Some(hackathon) => {
-                    let total_prize = self.get_total_prize(&hackathon);
-
-                    hackathons_with_prizes.push(Some(HackathonWithTotalPrize {
-                        hackathon,
-                        total_prize,
-                    }))
+    // Close a hackathon before it ended. Escrowed prizes go back to the owner, so no prize may
+    // have been paid out yet
+    pub fn cancel_hackathon(&mut self, hackathon_id: HackathonId) {
+        let initial_storage = env::storage_usage();
+
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert_eq!(env::predecessor_account_id(), hackathon.owner, "Not owner");
+        hackathon.assert_not_closed();
+
+        let mut refund = 0;
+        for category_id in hackathon.categories_list.to_vec() {
+            let category = match self.categories.get(&category_id) {
+                Some(category) => category,
+                None => continue,
+            };
+            for award_id in category.awards.iter() {
+                if let Some(mut award) = self.awards.get(award_id) {
+                    assert!(!award.is_awarded, "award awarded");
+                    assert!(award.payout.is_none(), "Payout proposed to the DAO");
+                    refund += award.funded.0;
+                    hackathon.prize_totals_mut(award.token()).funded.0 -= award.funded.0;
+                    award.funded = U128(0);
+                    self.awards.insert(award_id, &award);
                 }
-                None => hackathons_with_prizes.push(None),
             }
         }
+        if refund > 0 {
+            Promise::new(hackathon.owner.clone()).transfer(refund);
+        }
+
+        self.unindex_hackathon(&hackathon);
+        hackathon.cancelled = true;
+        self.hackathons.insert(&hackathon_id, &hackathon);
+        self.record_edits(
+            hackathon_id,
+            vec![Edit::new(
+                EditTarget::Hackathon,
+                "cancelled",
+                false.to_string(),
+                true.to_string(),
+            )],
+        );
 
-        hackathons_with_prizes
+        HackathonEvent::HackathonCancelled {
+            hackathon_id,
+            refund: U128(refund),
+        }
+        .emit();
+        self.charge_storage(initial_storage);
     }
 
-    pub fn get_detail_hackathon_by_id(&self, hackathon_id: HackathonId) -> Option<HackathonJson> {
-        let hackathon = self.hackathons.get(&hackathon_id).unwrap();
-        let mut pars = Vec::new();
-        let mut cats = Vec::new();
-        let mut subs = Vec::new();
+    // First step of an ownership transfer, `None` cancels a pending proposal
+    pub fn propose_owner(&mut self, hackathon_id: HackathonId, new_owner: Option<AccountId>) {
+        let initial_storage = env::storage_usage();
 
-        for p_id in hackathon.participants_list.iter() {
-            let rs = self.get_member_by_id(p_id.clone()).unwrap();
-            pars.push(rs);
-        }
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert_eq!(env::predecessor_account_id(), hackathon.owner, "Not owner");
 
-        for p_id in hackathon.categories_list.iter() {
-            let rs = self.get_category_by_id(*p_id).unwrap();
-            cats.push(rs);
+        if let Some(new_owner) = &new_owner {
+            assert_ne!(*new_owner, hackathon.owner, "Already owner");
+            assert!(self.members.contains_key(new_owner), "Not a member");
         }
 
-        for p_id in hackathon.submissions_list.iter() {
-            let rs = self.get_submission_by_id(*p_id).unwrap();
-            subs.push(rs);
-        }
+        hackathon.pending_owner = new_owner.clone();
+        self.hackathons.insert(&hackathon_id, &hackathon);
 
-        Some(HackathonJson {
-            participants: pars,
-            submissions: subs,
-            categories: cats,
-        })
+        HackathonEvent::OwnerProposed {
+            hackathon_id,
+            owner_id: new_owner,
+        }
+        .emit();
+        self.charge_storage(initial_storage);
     }
 
-    pub fn get_user_information_by_id(&self, id: AccountId) -> Option<MemberJsonDetail> {
-        match self.members.get(&id) {
-            Some(user) => {
-                let mut c_hack = Vec::new();
-                let mut p_hack = Vec::new();
-
-                for hackathon in user.created_hackathons {
-                    match self.get_hackathon_by_id(hackathon) {
-                        Some(hackathon) => {
-                            let total_prize = self.get_total_prize(&hackathon);
-
-                            c_hack.push(HackathonWithTotalPrize {
-                                hackathon,
-                                total_prize,
-                            })
-                        }
-                        None => (),
-                    }
-                }
-
-                for hackathon in user.joined_hackathons {
-                    match self.get_hackathon_by_id(hackathon) {
-                        Some(hackathon) => {
-                            let total_prize = self.get_total_prize(&hackathon);
+    // Second step of an ownership transfer, signed by the proposed owner
+    pub fn accept_owner(&mut self, hackathon_id: HackathonId) {
+        let initial_storage = env::storage_usage();
 
-                            p_hack.push(HackathonWithTotalPrize {
-                                hackathon,
-                                total_prize,
-                            })
-                        }
-                        None => (),
-                    }
-                }
+        let account_id = env::predecessor_account_id();
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert_eq!(
+            hackathon.pending_owner.as_ref(),
+            Some(&account_id),
+            "Not proposed owner"
+        );
+        assert!(
+            !hackathon.participants_list.contains(&account_id),
+            "Existing in hackathon"
+        );
 
-                Some(MemberJsonDetail {
-                    id: user.id,
-                    name: user.name,
-                    image: user.image,
-                    bio: user.bio,
-                    joined_hackathons: p_hack,
-                    created_hackathons: c_hack,
-                })
-            }
-            None => None,
-        }
-    }
-}
+        let mut new_owner = self.members.get(&account_id).expect("Not a member");
+        let old_owner_id = hackathon.owner.clone();
 
-// PRIVATE VIEW FUNCTION
-#[near_bindgen]
-impl HackathonContract {
-    fn get_hackathon_by_id(&self, hackathon_id: HackathonId) -> Option<Hackathon> {
-        if let Some(result) = self.hackathons.get(&hackathon_id) {
-            Some(result)
-        } else {
-            None
+        if let Some(mut old_owner) = self.members.get(&old_owner_id) {
+            old_owner.created_hackathons.retain(|h| *h != hackathon_id);
+            self.members.insert(&old_owner_id, &old_owner);
         }
-    }
 
-    fn get_category_by_id(&self, category_id: CategoryId) -> Option<CategoryJson> {
-        if let Some(result) = self.categories.get(&category_id) {
-            // let mut prizes: Vec<String> = Vec::new();
-            let mut awards = Vec::new();
+        new_owner.created_hackathons.push(hackathon_id);
+        self.members.insert(&account_id, &new_owner);
 
-            for i in result.awards.iter() {
-                // let prize = self.get_prize_by_id(*i).unwrap();
-                // prizes.push(prize);
-                let a = self.get_award_by_id(*i).unwrap();
+        hackathon.owner = account_id.clone();
+        hackathon.pending_owner = None;
+        hackathon.organizers.retain(|o| *o != account_id);
+        self.hackathons.insert(&hackathon_id, &hackathon);
 
-                awards.push(a);
-            }
+        let edit = Edit::new(
+            EditTarget::Hackathon,
+            "owner",
+            old_owner_id.to_string(),
+            account_id.to_string(),
+        );
+        self.record_edits(hackathon_id, vec![edit]);
 
-            let category_json: CategoryJson = CategoryJson {
-                name: result.name,
-                id: result.id,
-                awards, // prizes_list: prizes
-            };
-            Some(category_json)
-        } else {
-            None
+        HackathonEvent::OwnerAccepted {
+            hackathon_id,
+            old_owner_id,
+            owner_id: account_id,
         }
+        .emit();
+        self.charge_storage(initial_storage);
     }
 
-    fn get_award_by_id(&self, award_id: AwardId) -> Option<AwardJson> {
-        if let Some(rs) = self.awards.get(&award_id) {
-            match rs.winner {
-                Some(id) => {
-                    let s_json = self.get_submission_by_id(id);
-                    let a_json = AwardJson {
-                        name: rs.name,
-                        id: rs.id,
-                        price: rs.price,
-                        winner: s_json,
-                        is_awarded: rs.is_awarded,
-                    };
-                    Some(a_json)
-                }
-                None => Some(AwardJson {
-                    name: rs.name,
-                    id: rs.id,
-                    price: rs.price,
-                    winner: None,
-                    is_awarded: rs.is_awarded,
-                }),
-            }
-        } else {
-            None
-        }
-    }
+    // The DAO pays the prizes raised with `propose_award_payout`, `None` goes back to paying
+    // them with `award_winner`
+    pub fn set_payout_dao(&mut self, hackathon_id: HackathonId, dao_id: Option<AccountId>) {
+        let initial_storage = env::storage_usage();
+
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert_eq!(env::predecessor_account_id(), hackathon.owner, "Not owner");
+        assert!(!hackathon.cancelled, "Hackathon cancelled");
+
+        let edit = Edit::new(
+            EditTarget::Hackathon,
+            "payout_dao",
+            near_sdk::serde_json::to_string(&hackathon.payout_dao).unwrap(),
+            near_sdk::serde_json::to_string(&dao_id).unwrap(),
+        );
+        hackathon.payout_dao = dao_id.clone();
+        self.hackathons.insert(&hackathon_id, &hackathon);
+        self.record_edits(hackathon_id, vec![edit]);
 
-    fn get_member_by_id(&self, member_id: AccountId) -> Option<MemberJson> {
-        if let Some(result) = self.members.get(&member_id) {
-            Some(MemberJson {
-                name: result.name,
-                id: result.id,
-                image: result.image,
-                bio: result.bio,
-            })
-        } else {
-            None
+        HackathonEvent::PayoutDaoSet {
+            hackathon_id,
+            dao_id,
         }
+        .emit();
+        self.charge_storage(initial_storage);
     }
 
-    fn get_submission_by_id(&self, submission_id: SubmissionId) -> Option<SubmissionJson> {
-        if let Some(result) = self.submissions.get(&submission_id) {
-            let mut pars = Vec::new();
-            let mut cats = Vec::new();
+    pub fn join_hackathon(&mut self, hackathon_id: u64) {
+        let initial_storage = env::storage_usage();
+
+        let account_id = env::predecessor_account_id();
+        let mut member = self.members.get(&account_id).expect("Not a member");
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+
+        assert!(!hackathon.is_organizer(&account_id), "Can't join your own");
+        assert!(
+            !hackathon.participants_list.contains(&account_id),
+            "Existing in hackathon"
+        );
+        hackathon.assert_not_closed();
 
-            for p_id in result.categories.iter() {
-                // let cat = self.get_category_by_id(*p_id).unwrap();
-                let cat = self.categories.get(p_id).unwrap();
-                cats.push(cat);
-            }
+        hackathon.participants_list.insert(&account_id);
+        self.hackathons.insert(&hackathon_id, &hackathon);
 
-            for p_id in result.members.iter() {
-                let par = self.get_member_by_id(p_id.clone()).unwrap();
-                pars.push(par);
-            }
+        member.joined_hackathons.push(hackathon_id);
+        self.members.insert(&account_id, &member);
 
-            Some(SubmissionJson {
-                id: result.id,
-                categories: cats,
-                members: pars,
-                name: result.name,
-                link: result.link,
-                image: result.image,
-                description: result.description,
-                time: result.time,
-            })
-        } else {
-            None
+        HackathonEvent::ParticipantJoined {
+            hackathon_id,
+            account_id,
         }
+        .emit();
+
+        self.charge_storage(initial_storage);
     }
 
-    fn get_total_prize(&self, hackathon: &Hackathon) -> U128 {
-        let mut total = 0;
+    pub fn create_team(&mut self, hackathon_id: HackathonId, name: String) -> TeamId {
+        let initial_storage = env::storage_usage();
+
+        let account_id = env::predecessor_account_id();
+        let mut member = self.members.get(&account_id).expect("Not a member");
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+
+        assert!(
+            hackathon.participants_list.contains(&account_id),
+            "Not participated in hackathon"
+        );
+        hackathon.assert_not_closed();
+        assert!(
+            self.team_in_hackathon(&member, hackathon_id).is_none(),
+            "Already in a team"
+        );
+
+        let team_id = self.next_team_id;
+        self.next_team_id += 1;
+
+        let team = Team::new(team_id, hackathon_id, name);
+        self.insert_team(&team);
+
+        hackathon.teams_list.push(team_id);
+        self.hackathons.insert(&hackathon_id, &hackathon);
+
+        self.update_posts(&hackathon, |posts| {
+            posts.retain(|p| p.team_id.is_some() || p.author != account_id)
+        });
+
+        member.teams.push(team_id);
+        self.members.insert(&account_id, &member);
+
+        HackathonEvent::TeamCreated {
+            hackathon_id,
+            team_id,
+            account_id,
+        }
+        .emit();
+        self.charge_storage(initial_storage);
+
+        team_id
+    }
+
+    pub fn invite_to_team(&mut self, team_id: TeamId, account_id: AccountId) {
+        let initial_storage = env::storage_usage();
+
+        let mut team = self.teams.get(&team_id).expect("Team not exist");
+        assert!(
+            team.members.contains(&env::predecessor_account_id()),
+            "Not a member of the team"
+        );
+
+        let hackathon = self
+            .hackathons
+            .get(&team.hackathon_id)
+            .expect("Hackathon not exist");
+        hackathon.assert_not_closed();
+        self.assert_no_pending_payout(&hackathon, &team);
+        assert!(
+            hackathon.participants_list.contains(&account_id),
+            "Not participated in hackathon"
+        );
+        assert!(!team.invitations.contains(&account_id), "Already invited");
+        hackathon.settings.assert_team_has_room(team.members.len());
+
+        let mut invitee = self.members.get(&account_id).expect("Not a member");
+        assert!(
+            self.team_in_hackathon(&invitee, team.hackathon_id)
+                .is_none(),
+            "Already in a team"
+        );
+
+        team.invitations.push(account_id.clone());
+        team.record(account_id.clone(), TeamAction::Invited);
+        self.insert_team(&team);
+
+        invitee.invitations.push(team_id);
+        self.members.insert(&account_id, &invitee);
+
+        HackathonEvent::TeamMemberInvited {
+            hackathon_id: team.hackathon_id,
+            team_id,
+            account_id,
+        }
+        .emit();
+        self.charge_storage(initial_storage);
+    }
+
+    pub fn accept_team_invitation(&mut self, team_id: TeamId) {
+        let initial_storage = env::storage_usage();
+
+        let account_id = env::predecessor_account_id();
+        let mut team = self.teams.get(&team_id).expect("Team not exist");
+        assert!(team.invitations.contains(&account_id), "Not invited");
+
+        let hackathon = self
+            .hackathons
+            .get(&team.hackathon_id)
+            .expect("Hackathon not exist");
+        hackathon.assert_not_closed();
+        self.assert_no_pending_payout(&hackathon, &team);
+        assert!(
+            hackathon.participants_list.contains(&account_id),
+            "Not participated in hackathon"
+        );
+
+        hackathon.settings.assert_team_has_room(team.members.len());
+
+        let mut member = self.members.get(&account_id).expect("Not a member");
+        assert!(
+            self.team_in_hackathon(&member, team.hackathon_id).is_none(),
+            "Already in a team"
+        );
+
+        team.invitations.retain(|i| *i != account_id);
+        team.members.push(account_id.clone());
+        team.record(account_id.clone(), TeamAction::Joined);
+        self.insert_team(&team);
+        let submission_ids = self.sync_team_submissions(&hackathon, &team);
+
+        // The new member stops looking, and a full team stops recruiting
+        let is_full = hackathon
+            .settings
+            .max_team_size
+            .is_some_and(|max| team.members.len() >= max as usize);
+        self.update_posts(&hackathon, |posts| {
+            posts.retain(|p| match p.team_id {
+                Some(id) => !(id == team_id && is_full),
+                None => p.author != account_id,
+            })
+        });
+
+        member.invitations.retain(|t| *t != team_id);
+        member.teams.push(team_id);
+        self.members.insert(&account_id, &member);
+
+        HackathonEvent::TeamMemberJoined {
+            hackathon_id: team.hackathon_id,
+            team_id,
+            account_id,
+            submission_ids,
+        }
+        .emit();
+        self.charge_storage(initial_storage);
+    }
+
+    pub fn decline_team_invitation(&mut self, team_id: TeamId) {
+        let initial_storage = env::storage_usage();
+
+        let account_id = env::predecessor_account_id();
+        let mut team = self.teams.get(&team_id).expect("Team not exist");
+        assert!(team.invitations.contains(&account_id), "Not invited");
+
+        team.invitations.retain(|i| *i != account_id);
+        team.record(account_id.clone(), TeamAction::Declined);
+        self.insert_team(&team);
+
+        if let Some(mut member) = self.members.get(&account_id) {
+            member.invitations.retain(|t| *t != team_id);
+            self.members.insert(&account_id, &member);
+        }
+
+        HackathonEvent::TeamInvitationDeclined {
+            hackathon_id: team.hackathon_id,
+            team_id,
+            account_id,
+        }
+        .emit();
+        self.charge_storage(initial_storage);
+    }
+
+    pub fn leave_team(&mut self, team_id: TeamId) {
+        let initial_storage = env::storage_usage();
+
+        let account_id = env::predecessor_account_id();
+        let mut team = self.teams.get(&team_id).expect("Team not exist");
+        assert!(
+            team.members.contains(&account_id),
+            "Not a member of the team"
+        );
+
+        let hackathon = self
+            .hackathons
+            .get(&team.hackathon_id)
+            .expect("Hackathon not exist");
+        hackathon.assert_not_closed();
+        self.assert_no_pending_payout(&hackathon, &team);
+
+        team.members.retain(|m| *m != account_id);
+        assert!(
+            !team.members.is_empty() || team.submissions.is_empty(),
+            "Last member of a team with submissions, withdraw them first"
+        );
+
+        if team.leader == account_id {
+            if let Some(next_leader) = team.members.first() {
+                team.leader = next_leader.clone();
+            }
+        }
+        team.record(account_id.clone(), TeamAction::Left);
+
+        let mut submission_ids = Vec::new();
+        if team.members.is_empty() {
+            let mut hackathon = hackathon;
+            hackathon.teams_list.retain(|t| *t != team_id);
+            self.hackathons.insert(&team.hackathon_id, &hackathon);
+
+            self.update_posts(&hackathon, |posts| {
+                posts.retain(|p| p.team_id != Some(team_id))
+            });
+
+            for invitee_id in team.invitations.iter() {
+                if let Some(mut invitee) = self.members.get(invitee_id) {
+                    invitee.invitations.retain(|t| *t != team_id);
+                    self.members.insert(invitee_id, &invitee);
+                }
+            }
+            self.write_paid(team.payer.as_ref(), |contract| {
+                contract.teams.remove(&team_id)
+            });
+        } else {
+            self.hand_over_storage(&mut team, &account_id);
+            self.insert_team(&team);
+            submission_ids = self.sync_team_submissions(&hackathon, &team);
+        }
+
+        if let Some(mut member) = self.members.get(&account_id) {
+            member.teams.retain(|t| *t != team_id);
+            self.members.insert(&account_id, &member);
+        }
+
+        HackathonEvent::TeamMemberLeft {
+            hackathon_id: team.hackathon_id,
+            team_id,
+            account_id,
+            submission_ids,
+        }
+        .emit();
+        self.charge_storage(initial_storage);
+    }
+
+    pub fn post_looking_for_team(
+        &mut self,
+        hackathon_id: HackathonId,
+        skills: Vec<String>,
+        message: String,
+    ) {
+        let initial_storage = env::storage_usage();
+
+        let account_id = env::predecessor_account_id();
+        let member = self.members.get(&account_id).expect("Not a member");
+        let hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+
+        assert!(
+            hackathon.participants_list.contains(&account_id),
+            "Not participated in hackathon"
+        );
+        hackathon.assert_not_closed();
+        assert!(
+            self.team_in_hackathon(&member, hackathon_id).is_none(),
+            "Already in a team"
+        );
+
+        let post = Post::new(None, skills, message);
+        self.update_posts(&hackathon, |posts| {
+            posts.retain(|p| p.team_id.is_some() || p.author != account_id);
+            posts.push(post);
+        });
+
+        self.charge_storage(initial_storage);
+    }
+
+    pub fn post_looking_for_members(
+        &mut self,
+        team_id: TeamId,
+        skills: Vec<String>,
+        message: String,
+    ) {
+        let initial_storage = env::storage_usage();
+
+        let team = self.teams.get(&team_id).expect("Team not exist");
+        assert!(
+            team.members.contains(&env::predecessor_account_id()),
+            "Not a member of the team"
+        );
+
+        let hackathon = self
+            .hackathons
+            .get(&team.hackathon_id)
+            .expect("Hackathon not exist");
+        hackathon.assert_not_closed();
+        hackathon.settings.assert_team_has_room(team.members.len());
+
+        let post = Post::new(Some(team_id), skills, message);
+        self.update_posts(&hackathon, |posts| {
+            posts.retain(|p| p.team_id != Some(team_id));
+            posts.push(post);
+        });
+
+        self.charge_storage(initial_storage);
+    }
+
+    // Removes the caller's own post, or the post of `team_id` when given
+    pub fn remove_post(&mut self, hackathon_id: HackathonId, team_id: Option<TeamId>) {
+        let initial_storage = env::storage_usage();
+
+        let account_id = env::predecessor_account_id();
+        let hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+
+        if let Some(team_id) = team_id {
+            let team = self.teams.get(&team_id).expect("Team not exist");
+            assert!(
+                team.members.contains(&account_id),
+                "Not a member of the team"
+            );
+        }
+
+        self.update_posts(&hackathon, |posts| {
+            posts.retain(|p| match team_id {
+                Some(team_id) => p.team_id != Some(team_id),
+                None => p.team_id.is_some() || p.author != account_id,
+            })
+        });
+
+        self.charge_storage(initial_storage);
+    }
+
+    // Anyone may clear the board of a hackathon that is no longer building
+    pub fn prune_posts(&mut self, hackathon_id: HackathonId) {
+        let initial_storage = env::storage_usage();
+
+        let hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert_eq!(
+            hackathon.phase(),
+            HackathonPhase::Ended,
+            "Hackathon not ended"
+        );
+
+        self.update_posts(&hackathon, |posts| posts.clear());
+
+        self.charge_storage(initial_storage);
+    }
+
+    pub fn submit_project(
+        &mut self,
+        hackathon_id: HackathonId,
+        team_id: TeamId,
+        categories: Vec<CategoryId>,
+        name: String,
+        description: String,
+        image: String,
+        link: Vec<String>,
+        time: Timestamp,
+        artifacts: Option<Vec<Artifact>>,
+    ) {
+        let initial_storage = env::storage_usage();
+
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert!(
+            env::block_timestamp() < hackathon.end,
+            "Submission deadline passed"
+        );
+        assert!(!hackathon.cancelled, "Hackathon cancelled");
+
+        let mut team = self.teams.get(&team_id).expect("Team not exist");
+        assert_eq!(team.hackathon_id, hackathon_id, "Team is not in hackathon");
+        assert!(
+            team.members.contains(&env::predecessor_account_id()),
+            "Not a member of the team"
+        );
+
+        hackathon.settings.assert_team_size(team.members.len());
+        hackathon
+            .settings
+            .assert_team_can_submit(team.submissions.len());
+        hackathon.settings.assert_category_count(categories.len());
+        self.assert_categories_in_hackathon(&hackathon, &categories);
+
+        let members = team.members.clone();
+        for member in members.iter() {
+            assert!(
+                self.members.contains_key(member),
+                "Participant not a member"
+            );
+            assert!(
+                hackathon.participants_list.contains(member),
+                "Not participated in hackathon"
+            );
+        }
+
+        let submission_id: SubmissionId = self.next_submission_id;
+        let mut submission = Submission::new(
+            submission_id,
+            team_id,
+            categories,
+            members,
+            name,
+            description,
+            image,
+            collect_artifacts(link, artifacts.unwrap_or_default()),
+            time,
+        );
+        let revision = submission.next_revision();
+        self.insert_revision(submission_id, &revision);
+        self.insert_submission(&submission);
+
+        hackathon.submissions_list.insert(&submission_id);
+        self.hackathons.insert(&hackathon_id, &hackathon);
+
+        team.submissions.push(submission_id);
+        self.insert_team(&team);
+
+        self.next_submission_id += 1;
+
+        HackathonEvent::ProjectSubmitted {
+            hackathon_id,
+            submission_id,
+            team_id,
+            account_ids: submission.members,
+            category_ids: submission.categories,
+        }
+        .emit();
+
+        self.charge_storage(initial_storage);
+    }
+
+    pub fn update_submission(
+        &mut self,
+        submission_id: SubmissionId,
+        payload: SubmissionUpdatePayload,
+    ) {
+        let initial_storage = env::storage_usage();
+
+        let mut submission = self
+            .submissions
+            .get(&submission_id)
+            .expect("Submission not exist");
+        assert!(
+            submission.members.contains(&env::predecessor_account_id()),
+            "Not a member of the submission"
+        );
+
+        let team = self.teams.get(&submission.team_id).expect("Team not exist");
+        let hackathon = self
+            .hackathons
+            .get(&team.hackathon_id)
+            .expect("Hackathon not exist");
+        assert!(
+            env::block_timestamp() < hackathon.end,
+            "Submission deadline passed"
+        );
+        assert!(!hackathon.cancelled, "Hackathon cancelled");
+
+        if let Some(name) = payload.name {
+            submission.name = name;
+        }
+        if let Some(description) = payload.description {
+            submission.description = description;
+        }
+        if let Some(image) = payload.image {
+            submission.image = image;
+        }
+        if payload.link.is_some() || payload.artifacts.is_some() {
+            submission.artifacts = collect_artifacts(
+                payload.link.unwrap_or_default(),
+                payload.artifacts.unwrap_or_default(),
+            );
+        }
+        if let Some(categories) = payload.categories {
+            hackathon.settings.assert_category_count(categories.len());
+            self.assert_categories_in_hackathon(&hackathon, &categories);
+            submission.categories = categories;
+        }
+        if let Some(content) = payload.content {
+            content.assert_valid();
+            submission.content = Some(content);
+        }
+
+        let revision = submission.next_revision();
+        self.insert_revision(submission_id, &revision);
+        self.insert_submission(&submission);
+
+        HackathonEvent::SubmissionUpdated {
+            hackathon_id: team.hackathon_id,
+            submission_id,
+            revision: revision.number,
+        }
+        .emit();
+        self.charge_storage(initial_storage);
+    }
+
+    // Pin the git commit judges should review, the anchor in the deadline revision is the final one
+    pub fn anchor_commit(&mut self, submission_id: SubmissionId, commit_hash: String) {
+        let initial_storage = env::storage_usage();
+
+        let mut submission = self
+            .submissions
+            .get(&submission_id)
+            .expect("Submission not exist");
+        assert!(
+            submission.members.contains(&env::predecessor_account_id()),
+            "Not a member of the submission"
+        );
+
+        let team = self.teams.get(&submission.team_id).expect("Team not exist");
+        let hackathon = self
+            .hackathons
+            .get(&team.hackathon_id)
+            .expect("Hackathon not exist");
+        assert!(
+            env::block_timestamp() < hackathon.end,
+            "Submission deadline passed"
+        );
+        assert!(!hackathon.cancelled, "Hackathon cancelled");
+
+        let commit = CommitAnchor::new(commit_hash);
+        submission.commit = Some(commit.clone());
+
+        let revision = submission.next_revision();
+        self.insert_revision(submission_id, &revision);
+        self.insert_submission(&submission);
+
+        HackathonEvent::CommitAnchored {
+            hackathon_id: team.hackathon_id,
+            submission_id,
+            revision: revision.number,
+            commit_hash: commit.hash,
+        }
+        .emit();
+        self.charge_storage(initial_storage);
+    }
+
+    pub fn judge_winner(
+        &mut self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        submission_id: SubmissionId,
+    ) {
+        let initial_storage = env::storage_usage();
+
+        let account_id = env::predecessor_account_id();
+        let hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert!(hackathon.is_organizer(&account_id), "Not organizer");
+        assert!(!hackathon.cancelled, "Hackathon cancelled");
+
+        let mut award = self.award_in_hackathon(&hackathon, category_id, award_id);
+        assert_eq!(award.winner, None, "Winner judged");
+
+        assert!(
+            hackathon.submissions_list.contains(&submission_id),
+            "Submission is not in hackathon"
+        );
+        let submission = self
+            .submissions
+            .get(&submission_id)
+            .expect("Submission not exist");
+        assert!(
+            submission.categories.contains(&category_id),
+            "Submission is not in category"
+        );
+
+        for winner in submission.members.iter() {
+            assert!(self.members.contains_key(winner), "Winner is not a member");
+            assert!(
+                hackathon.participants_list.contains(winner),
+                "Not participated"
+            );
+        }
+
+        award.winner = Some(submission_id);
+        self.awards.insert(&award_id, &award);
+
+        HackathonEvent::WinnerJudged {
+            hackathon_id,
+            category_id,
+            award_id,
+            submission_id,
+        }
+        .emit();
+
+        self.charge_storage(initial_storage);
+    }
+
+    pub fn leave_hackathon(&mut self, hackathon_id: HackathonId) {
+        let initial_storage = env::storage_usage();
+
+        let account_id = env::predecessor_account_id();
+        let mut member = self.members.get(&account_id).expect("Not a member");
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+
+        assert!(
+            hackathon.participants_list.contains(&account_id),
+            "Not participated in hackathon"
+        );
+        assert!(
+            env::block_timestamp() < hackathon.end,
+            "Submission deadline passed"
+        );
+
+        // Submissions are made by teams, so leaving the team first keeps them consistent
+        assert!(
+            self.team_in_hackathon(&member, hackathon_id).is_none(),
+            "Member of a team, leave it first"
+        );
+
+        hackathon.participants_list.remove(&account_id);
+        self.hackathons.insert(&hackathon_id, &hackathon);
+
+        self.update_posts(&hackathon, |posts| {
+            posts.retain(|p| p.team_id.is_some() || p.author != account_id)
+        });
+
+        member.joined_hackathons.retain(|h| *h != hackathon_id);
+        self.members.insert(&account_id, &member);
+
+        HackathonEvent::ParticipantLeft {
+            hackathon_id,
+            account_id,
+        }
+        .emit();
+        self.charge_storage(initial_storage);
+    }
+
+    pub fn withdraw_submission(&mut self, hackathon_id: HackathonId, submission_id: SubmissionId) {
+        let initial_storage = env::storage_usage();
+
+        let account_id = env::predecessor_account_id();
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+
+        assert!(
+            hackathon.submissions_list.contains(&submission_id),
+            "Submission is not in hackathon"
+        );
+
+        let submission = self
+            .submissions
+            .get(&submission_id)
+            .expect("Submission not exist");
+        assert!(
+            submission.members.contains(&account_id),
+            "Not a member of the submission"
+        );
+
+        // Drop the submission from every award it has won but not been paid for yet
+        let mut award_ids = Vec::new();
+        for (award_id, mut award) in self.awards_won(&hackathon, submission_id) {
+            assert!(!award.is_awarded, "Award already paid to submission");
+            assert!(award.payout.is_none(), "Payout proposed to the DAO");
+
+            award.winner = None;
+            self.awards.insert(&award_id, &award);
+            award_ids.push(award_id);
+        }
+
+        hackathon.submissions_list.remove(&submission_id);
+        self.hackathons.insert(&hackathon_id, &hackathon);
+
+        if let Some(mut team) = self.teams.get(&submission.team_id) {
+            team.submissions.retain(|s| *s != submission_id);
+            self.insert_team(&team);
+        }
+
+        for number in 0..submission.revisions {
+            if let Some(revision) = self.submission_revisions.get(&(submission_id, number)) {
+                self.write_paid(Some(&revision.editor), |contract| {
+                    contract
+                        .submission_revisions
+                        .remove(&(submission_id, number));
+                });
+            }
+        }
+        self.write_paid(submission.payer.as_ref(), |contract| {
+            contract.submissions.remove(&submission_id)
+        });
+
+        HackathonEvent::SubmissionWithdrawn {
+            hackathon_id,
+            submission_id,
+            team_id: submission.team_id,
+            award_ids,
+        }
+        .emit();
+        self.charge_storage(initial_storage);
+    }
+
+    pub fn update_member(
+        &mut self,
+        name: Option<String>,
+        image: Option<String>,
+        bio: Option<String>,
+    ) -> Option<MemberJson> {
+        let initial_storage = env::storage_usage();
+
+        let id: AccountId = env::predecessor_account_id();
+        assert!(self.members_list.contains(&id), "Member not exist");
+        match self.members.get(&id) {
+            Some(mut mem) => {
+                if let Some(name) = name {
+                    mem.name = name;
+                }
+                if let Some(image) = image {
+                    mem.image = Some(image);
+                }
+                if let Some(bio) = bio {
+                    mem.bio = Some(bio);
+                }
+
+                self.members.insert(&id, &mem);
+                self.charge_storage(initial_storage);
+
+                Some(MemberJson {
+                    id,
+                    name: mem.name,
+                    image: mem.image,
+                    bio: mem.bio,
+                })
+            }
+            None => None,
+        }
+    }
+}
+
+// MIGRATION FUNCTION
+#[near_bindgen]
+impl HackathonContract {
+    // Upgrade the state of an older deployment in place, converting up to `limit` entities per
+    // call. Call again until `get_state_version` reports no pending migration. `upgrade` calls
+    // it from the contract account, the owner may call it to resume
+    #[init(ignore_state)]
+    pub fn migrate(limit: Option<u64>) -> Self {
+        let state = env::storage_read(b"STATE").expect("Contract is not initialized");
+        let mut contract = if let Ok(contract) = Self::try_from_slice(&state) {
+            contract
+        } else {
+            Self::from_v0(
+                HackathonContractV0::try_from_slice(&state).expect("Unknown contract state"),
+            )
+        };
+
+        let caller = env::predecessor_account_id();
+        assert!(
+            caller == env::current_account_id() || caller == contract.owner_id,
+            "Not owner"
+        );
+
+        contract.state_version = STATE_VERSION;
+        contract.migrate_batch(limit.unwrap_or(DEFAULT_MIGRATION_LIMIT));
+        contract
+    }
+
+    // Members are only found through the hackathons they took part in and the old list slots
+    // that were not overwritten. Members of neither are added back here
+    pub fn repair_members_list(&mut self, account_ids: Vec<AccountId>) {
+        self.assert_contract_owner();
+        self.repair_members(account_ids);
+    }
+}
+
+// OWNER FUNCTION
+#[near_bindgen]
+impl HackathonContract {
+    // Pass `None` to withdraw a pending transfer
+    pub fn propose_contract_owner(&mut self, owner_id: Option<AccountId>) {
+        self.assert_contract_owner();
+        self.pending_owner_id = owner_id;
+    }
+
+    pub fn accept_contract_owner(&mut self) {
+        let account_id = env::predecessor_account_id();
+        assert_eq!(
+            self.pending_owner_id.as_ref(),
+            Some(&account_id),
+            "Not the pending owner"
+        );
+
+        self.owner_id = account_id;
+        self.pending_owner_id = None;
+    }
+
+    // The delay can only be raised, lowering it would skip the review of a staged upgrade
+    pub fn set_upgrade_delay(&mut self, upgrade_delay: U64) {
+        self.assert_contract_owner();
+        assert!(
+            upgrade_delay.0 >= self.upgrade_delay,
+            "Upgrade delay can only be raised"
+        );
+
+        self.upgrade_delay = upgrade_delay.0;
+    }
+
+    // Announce the sha256 of the code `upgrade` will deploy, restarting the timelock
+    pub fn stage_upgrade(&mut self, code_hash: Base58CryptoHash) {
+        self.assert_contract_owner();
+
+        let now = env::block_timestamp();
+        self.staged_upgrade = Some(StagedUpgrade {
+            code_hash,
+            staged_at: now,
+            deployable_at: now + self.upgrade_delay,
+        });
+    }
+
+    pub fn cancel_upgrade(&mut self) {
+        self.assert_contract_owner();
+        self.staged_upgrade = None;
+    }
+
+    // Runs on the deployed code once the deploy and `migrate` batch finished. A failed batch is
+    // reverted as a whole, so the staged upgrade is kept for another attempt
+    #[private]
+    pub fn on_upgrade_deployed(&mut self, code_hash: Base58CryptoHash) -> bool {
+        let deployed = near_sdk::is_promise_success();
+        if deployed
+            && self
+                .staged_upgrade
+                .as_ref()
+                .is_some_and(|staged| staged.code_hash == code_hash)
+        {
+            self.staged_upgrade = None;
+        }
+
+        deployed
+    }
+}
+
+// PAYABLE FUNCTION
+#[near_bindgen]
+impl HackathonContract {
+    #[payable]
+    pub fn fund_award(
+        &mut self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+    ) {
+        let initial_storage = env::storage_usage();
+
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert_eq!(env::predecessor_account_id(), hackathon.owner, "Not owner");
+        assert!(!hackathon.cancelled, "Hackathon cancelled");
+        let mut award = self.award_in_hackathon(&hackathon, category_id, award_id);
+        assert!(!award.is_awarded, "award awarded");
+        assert!(award.payout.is_none(), "Payout proposed to the DAO");
+
+        let funded = award.funded.0 + env::attached_deposit();
+        assert!(funded <= award.price.0, "Deposit exceeds the award");
+
+        hackathon.prize_totals_mut(award.token()).funded.0 += env::attached_deposit();
+        award.funded = U128(funded);
+        self.awards.insert(&award_id, &award);
+        self.hackathons.insert(&hackathon_id, &hackathon);
+
+        HackathonEvent::AwardFunded {
+            hackathon_id,
+            category_id,
+            award_id,
+            token: award.token(),
+            amount: U128(env::attached_deposit()),
+            funded: award.funded,
+        }
+        .emit();
+        self.charge_storage(initial_storage);
+    }
+
+    #[payable]
+    pub fn award_winner(
+        &mut self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+    ) {
+        let initial_storage = env::storage_usage();
+
+        let account_id = env::predecessor_account_id();
+        let mut hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert_eq!(account_id, hackathon.owner, "Not owner");
+        assert!(!hackathon.cancelled, "Hackathon cancelled");
+
+        let mut award = self.award_in_hackathon(&hackathon, category_id, award_id);
+        assert!(!award.is_awarded, "award awarded");
+        assert!(award.payout.is_none(), "Payout proposed to the DAO");
+        // Whatever was escrowed with `fund_award` is topped up by the attached deposit
+        assert_eq!(
+            U128(env::attached_deposit()),
+            U128(award.price.0 - award.funded.0),
+            "attached deposit should be equal to the unfunded part of the award"
+        );
+
+        let winner = award.winner.expect("Winner not judged");
+        let receiver = self.winner_receiver(winner);
+
+        Promise::new(receiver.clone()).transfer(award.price.into());
+        let totals = hackathon.prize_totals_mut(award.token());
+        totals.funded.0 += award.price.0 - award.funded.0;
+        totals.paid.0 += award.price.0;
+        award.funded = award.price;
+        award.is_awarded = true;
+
+        self.awards.insert(&award_id, &award);
+        self.hackathons.insert(&hackathon_id, &hackathon);
+
+        HackathonEvent::AwardPaid {
+            hackathon_id,
+            category_id,
+            award_id,
+            submission_id: winner,
+            receiver_id: receiver,
+            token: award.token(),
+            amount: award.price,
+        }
+        .emit();
+
+        self.charge_storage(initial_storage);
+    }
+}
+
+// DAO PAYOUT FUNCTION
+#[near_bindgen]
+impl HackathonContract {
+    // Raise the prize of a judged award as a transfer proposal of the hackathon's payout DAO.
+    // The attached deposit is the proposal bond of the DAO, it is refunded if the proposal fails
+    #[payable]
+    pub fn propose_award_payout(
+        &mut self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+    ) -> Promise {
+        let initial_storage = env::storage_usage();
+
+        let account_id = env::predecessor_account_id();
+        let hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert!(hackathon.is_organizer(&account_id), "Not organizer");
+        assert!(!hackathon.cancelled, "Hackathon cancelled");
+        let dao_id = hackathon.payout_dao.clone().expect("No payout DAO");
+
+        let mut award = self.award_in_hackathon(&hackathon, category_id, award_id);
+        assert!(!award.is_awarded, "award awarded");
+        assert!(award.payout.is_none(), "Payout proposed to the DAO");
+        // Escrowed prizes are paid by `award_winner`
+        assert_eq!(award.funded.0, 0, "Award funded");
+        let winner = award.winner.expect("Winner not judged");
+        let receiver = self.winner_receiver(winner);
+
+        award.payout = Some(AwardPayout::Proposing {
+            dao_id: dao_id.clone(),
+            receiver_id: receiver.clone(),
+        });
+        self.awards.insert(&award_id, &award);
+
+        self.charge_storage(initial_storage);
+
+        let proposal = ProposalInput {
+            description: format!(
+                "Prize of award {} in hackathon {} ({}/{}/{})",
+                award.name, hackathon.name, hackathon_id, category_id, award_id
+            ),
+            kind: ProposalKind::Transfer {
+                token_id: String::new(),
+                receiver_id: receiver,
+                amount: award.price,
+                msg: None,
+            },
+        };
+        let bond = env::attached_deposit();
+        ext_dao::ext(dao_id)
+            .with_attached_deposit(bond)
+            .with_static_gas(GAS_FOR_DAO_CALL)
+            .add_proposal(proposal)
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_DAO_CALLBACK)
+                    .on_payout_proposed(
+                        hackathon_id,
+                        category_id,
+                        award_id,
+                        account_id,
+                        U128(bond),
+                    ),
+            )
+    }
+
+    // Read the status of a proposed payout from the DAO. An approved proposal pays the award, a
+    // proposal that can no longer pass is forgotten so the payout can be proposed again
+    pub fn check_award_payout(
+        &mut self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+    ) -> Promise {
+        let hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        let award = self.award_in_hackathon(&hackathon, category_id, award_id);
+        assert!(!award.is_awarded, "award awarded");
+        let (dao_id, proposal_id) = match award.payout {
+            Some(AwardPayout::Proposed {
+                dao_id,
+                proposal_id,
+                ..
+            }) => (dao_id, proposal_id),
+            _ => env::panic_str("Payout not proposed"),
+        };
+
+        ext_dao::ext(dao_id)
+            .with_static_gas(GAS_FOR_DAO_CALL)
+            .get_proposal(proposal_id)
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_DAO_CALLBACK)
+                    .on_payout_checked(hackathon_id, category_id, award_id, proposal_id),
+            )
+    }
+
+    // Forget a payout whose `add_proposal` callback never recorded the proposal, so it can be
+    // proposed again. A proposal the DAO did create must be removed in the DAO first
+    pub fn reset_award_payout(
+        &mut self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+    ) {
+        let initial_storage = env::storage_usage();
+
+        let hackathon = self
+            .hackathons
+            .get(&hackathon_id)
+            .expect("Hackathon not exist");
+        assert_eq!(env::predecessor_account_id(), hackathon.owner, "Not owner");
+
+        let mut award = self.award_in_hackathon(&hackathon, category_id, award_id);
+        assert!(
+            matches!(award.payout, Some(AwardPayout::Proposing { .. })),
+            "Payout not being proposed"
+        );
+        award.payout = None;
+        self.awards.insert(&award_id, &award);
+
+        self.charge_storage(initial_storage);
+    }
+
+    // Record the id of the proposal raised by `propose_award_payout`
+    #[private]
+    pub fn on_payout_proposed(
+        &mut self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        proposer: AccountId,
+        bond: U128,
+        #[callback_result] proposal_id: Result<u64, PromiseError>,
+    ) -> Option<u64> {
+        let mut award = self.awards.get(&award_id).expect("Award not exist");
+        let (dao_id, receiver_id) = match award.payout.take() {
+            Some(AwardPayout::Proposing {
+                dao_id,
+                receiver_id,
+            }) => (dao_id, receiver_id),
+            _ => env::panic_str("Payout not being proposed"),
+        };
+
+        let proposal_id = match proposal_id {
+            Ok(proposal_id) => proposal_id,
+            Err(_) => {
+                self.awards.insert(&award_id, &award);
+                if bond.0 > 0 {
+                    Promise::new(proposer).transfer(bond.0);
+                }
+                return None;
+            }
+        };
+
+        award.payout = Some(AwardPayout::Proposed {
+            dao_id: dao_id.clone(),
+            proposal_id,
+            receiver_id,
+        });
+        self.awards.insert(&award_id, &award);
+
+        HackathonEvent::AwardPayoutProposed {
+            hackathon_id,
+            category_id,
+            award_id,
+            dao_id,
+            proposal_id,
+        }
+        .emit();
+
+        Some(proposal_id)
+    }
+
+    // Apply the status of the proposal read by `check_award_payout`
+    #[private]
+    pub fn on_payout_checked(
+        &mut self,
+        hackathon_id: HackathonId,
+        category_id: CategoryId,
+        award_id: AwardId,
+        proposal_id: u64,
+        #[callback_result] proposal: Result<Proposal, PromiseError>,
+    ) -> Option<ProposalStatus> {
+        let status = proposal.ok()?.status;
+        let mut award = self.awards.get(&award_id).expect("Award not exist");
+        // The award may have been proposed again while the status was read
+        let receiver_id = match &award.payout {
+            Some(AwardPayout::Proposed {
+                proposal_id: proposed,
+                receiver_id,
+                ..
+            }) if *proposed == proposal_id => receiver_id.clone(),
+            _ => return None,
+        };
+
+        match status {
+            ProposalStatus::InProgress => return Some(status),
+            ProposalStatus::Approved => {
+                let mut hackathon = self
+                    .hackathons
+                    .get(&hackathon_id)
+                    .expect("Hackathon not exist");
+                let winner = award.winner.expect("Winner not judged");
+                hackathon.prize_totals_mut(award.token()).paid.0 += award.price.0;
+                award.is_awarded = true;
+                // Paid for good, a later check finds no proposal to apply again
+                award.payout = None;
+                self.hackathons.insert(&hackathon_id, &hackathon);
+
+                HackathonEvent::AwardPaid {
+                    hackathon_id,
+                    category_id,
+                    award_id,
+                    submission_id: winner,
+                    receiver_id,
+                    token: award.token(),
+                    amount: award.price,
+                }
+                .emit();
+            }
+            // Rejected, removed, expired, moved and failed proposals can not pay any more
+            _ => {
+                award.payout = None;
+                HackathonEvent::AwardPayoutClosed {
+                    hackathon_id,
+                    category_id,
+                    award_id,
+                    proposal_id,
+                    status,
+                }
+                .emit();
+            }
+        }
+        self.awards.insert(&award_id, &award);
+
+        Some(status)
+    }
+}
+
+// STORAGE MANAGEMENT (NEP-145)
+#[near_bindgen]
+impl HackathonContract {
+    #[payable]
+    pub fn storage_deposit(
+        &mut self,
+        account_id: Option<AccountId>,
+        registration_only: Option<bool>,
+    ) -> StorageBalance {
+        let amount = env::attached_deposit();
+        let account_id = account_id.unwrap_or_else(env::predecessor_account_id);
+        let registration_only = registration_only.unwrap_or(false);
+        let min = self.storage_balance_bounds().min.0;
+
+        let mut account = match self.storage_accounts.get(&account_id) {
+            Some(account) => {
+                if registration_only {
+                    if amount > 0 {
+                        Promise::new(env::predecessor_account_id()).transfer(amount);
+                    }
+                    return account.balance();
+                }
+                account
+            }
+            None => {
+                assert!(
+                    amount >= min,
+                    "The attached deposit is less than the minimum storage balance of {}",
+                    min
+                );
+                StorageAccount::default()
+            }
+        };
+
+        let deposit = if registration_only {
+            if amount > min {
+                Promise::new(env::predecessor_account_id()).transfer(amount - min);
+            }
+            min
+        } else {
+            amount
+        };
+        account.total += deposit;
+        self.storage_accounts.insert(&account_id, &account);
+
+        account.balance()
+    }
+
+    #[payable]
+    pub fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance {
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+        let mut account = self
+            .storage_accounts
+            .get(&account_id)
+            .expect("Storage not registered");
+
+        let available = account.available();
+        let amount = amount.map_or(available, |amount| amount.0);
+        assert!(
+            amount <= available,
+            "Cannot withdraw {}, only {} is available",
+            amount,
+            available
+        );
+
+        if amount > 0 {
+            account.total -= amount;
+            self.storage_accounts.insert(&account_id, &account);
+            Promise::new(account_id).transfer(amount);
+        }
+
+        account.balance()
+    }
+
+    pub fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
+        self.storage_accounts
+            .get(&account_id)
+            .map(|account| account.balance())
+    }
+
+    pub fn storage_balance_bounds(&self) -> StorageBalanceBounds {
+        StorageBalanceBounds {
+            min: U128(MIN_STORAGE_BYTES as Balance * env::storage_byte_cost()),
+            max: None,
+        }
+    }
+}
+
+// PUBLIC VIEW FUNCTION
+#[near_bindgen]
+impl HackathonContract {
+    // Every field of each hackathon, `get_hackathon_summaries` lists them with counts instead
+    pub fn get_all_hackathons(
+        &self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<Option<HackathonWithTotalPrize>> {
+        let hackathons = self.hackathons_list.as_vector();
+        let total = hackathons.len();
+
+        Page {
+            items: page_range(total, from_index, limit)
+                .filter_map(|index| hackathons.get(index))
+                .map(|hackathon_id| self.get_hackathon(hackathon_id))
+                .collect(),
+            total,
+        }
+    }
+
+    pub fn get_all_members(&self, from_index: Option<u64>, limit: Option<u64>) -> Page<MemberJson> {
+        let members = self.members_list.as_vector();
+        let total = members.len();
+
+        Page {
+            items: page_range(total, from_index, limit)
+                .filter_map(|index| members.get(index))
+                .filter_map(|account_id| self.get_member_by_id(account_id))
+                .collect(),
+            total,
+        }
+    }
+
+    pub fn get_hackathon_participants(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<MemberJson> {
+        let hackathon = match self.hackathons.get(&hackathon_id) {
+            Some(hackathon) => hackathon,
+            None => return Page::from_vec(Vec::new(), from_index, limit),
+        };
+        let participants = hackathon.participants_list.as_vector();
+        let total = participants.len();
+
+        Page {
+            items: page_range(total, from_index, limit)
+                .filter_map(|index| participants.get(index))
+                .filter_map(|account_id| self.get_member_by_id(account_id))
+                .collect(),
+            total,
+        }
+    }
+
+    pub fn get_hackathon_submissions(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<SubmissionJson> {
+        let hackathon = match self.hackathons.get(&hackathon_id) {
+            Some(hackathon) => hackathon,
+            None => return Page::from_vec(Vec::new(), from_index, limit),
+        };
+        let submissions = hackathon.submissions_list.as_vector();
+        let total = submissions.len();
+
+        Page {
+            items: page_range(total, from_index, limit)
+                .filter_map(|index| submissions.get(index))
+                .filter_map(|submission_id| self.get_submission_by_id(submission_id))
+                .collect(),
+            total,
+        }
+    }
+
+    pub fn get_member_joined_hackathons(
+        &self,
+        account_id: AccountId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<HackathonSummary> {
+        let joined = self
+            .members
+            .get(&account_id)
+            .map(|member| member.joined_hackathons)
+            .unwrap_or_default();
+        self.get_hackathons_page(joined, from_index, limit)
+    }
+
+    pub fn get_member_created_hackathons(
+        &self,
+        account_id: AccountId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<HackathonSummary> {
+        let created = self
+            .members
+            .get(&account_id)
+            .map(|member| member.created_hackathons)
+            .unwrap_or_default();
+        self.get_hackathons_page(created, from_index, limit)
+    }
+
+    pub fn get_hackathons_by_tag(
+        &self,
+        tag: String,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<HackathonSummary> {
+        let hackathon_ids = match self.hackathons_by_tag.get(&tag) {
+            Some(hackathon_ids) => hackathon_ids,
+            None => return Page::from_vec(Vec::new(), from_index, limit),
+        };
+        let hackathon_ids = hackathon_ids.as_vector();
+        let total = hackathon_ids.len();
+
+        Page {
+            items: page_range(total, from_index, limit)
+                .filter_map(|index| hackathon_ids.get(index))
+                .filter_map(|hackathon_id| self.get_hackathon_summary(hackathon_id))
+                .collect(),
+            total,
+        }
+    }
+
+    // Hackathons starting in `[from, to)`, earliest first. The next page resumes after the
+    // `(start, id)` key the previous one returned
+    pub fn get_hackathons_in_range(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+        after: Option<(Timestamp, HackathonId)>,
+        limit: Option<u64>,
+    ) -> CursorPage<HackathonSummary, (Timestamp, HackathonId)> {
+        let lower = match after {
+            Some(key) if key >= (from, 0) => Bound::Excluded(key),
+            _ => Bound::Included((from, 0)),
+        };
+        let upper = (to, 0);
+        let empty = match lower {
+            Bound::Excluded(key) => key >= upper,
+            _ => from >= to,
+        };
+        if empty {
+            return CursorPage {
+                items: Vec::new(),
+                next: None,
+            };
+        }
+
+        let hackathons = self
+            .hackathons_by_start
+            .range((lower, Bound::Excluded(upper)))
+            .filter_map(|(key, _)| Some((key, self.get_hackathon_summary(key.1)?)));
+
+        cursor_page(hackathons, limit)
+    }
+
+    pub fn get_upcoming_hackathons(
+        &self,
+        sort: Option<HackathonSort>,
+        after: Option<CalendarCursor>,
+        limit: Option<u64>,
+    ) -> CursorPage<HackathonSummary, CalendarCursor> {
+        let now = env::block_timestamp();
+        let sort = sort.unwrap_or_default();
+
+        match sort {
+            HackathonSort::Start => {
+                let first = (now, HackathonId::MAX);
+                let after = after.map_or(first, |cursor| cursor.start_key().max(first));
+                let hackathons = self
+                    .hackathons_by_start
+                    .range((Bound::Excluded(after), Bound::Unbounded))
+                    .map(start_entry);
+
+                self.get_calendar_page(hackathons, |_| true, limit)
+            }
+            HackathonSort::Prize => self.get_calendar_page(
+                self.get_prize_ordered_hackathons(after),
+                |hackathon| hackathon.start > now,
+                limit,
+            ),
+        }
+    }
+
+    pub fn get_ongoing_hackathons(
+        &self,
+        sort: Option<HackathonSort>,
+        after: Option<CalendarCursor>,
+        limit: Option<u64>,
+    ) -> CursorPage<HackathonSummary, CalendarCursor> {
+        let now = env::block_timestamp();
+        let sort = sort.unwrap_or_default();
+
+        match sort {
+            HackathonSort::Start => self.get_calendar_page(
+                self.get_started_hackathons(now, after),
+                |hackathon| hackathon.end > now,
+                limit,
+            ),
+            HackathonSort::Prize => self.get_calendar_page(
+                self.get_prize_ordered_hackathons(after),
+                |hackathon| hackathon.start <= now && hackathon.end > now,
+                limit,
+            ),
+        }
+    }
+
+    // Past hackathons in start order list the latest first
+    pub fn get_past_hackathons(
+        &self,
+        sort: Option<HackathonSort>,
+        after: Option<CalendarCursor>,
+        limit: Option<u64>,
+    ) -> CursorPage<HackathonSummary, CalendarCursor> {
+        let now = env::block_timestamp();
+        let sort = sort.unwrap_or_default();
+
+        match sort {
+            HackathonSort::Start => {
+                let last = (now, HackathonId::MAX);
+                let after = after.map_or(last, |cursor| cursor.start_key().min(last));
+                let hackathons = self
+                    .hackathons_by_start
+                    .iter_rev_from(after)
+                    .map(start_entry);
+
+                self.get_calendar_page(hackathons, |hackathon| hackathon.end <= now, limit)
+            }
+            HackathonSort::Prize => self.get_calendar_page(
+                self.get_prize_ordered_hackathons(after),
+                |hackathon| hackathon.end <= now,
+                limit,
+            ),
+        }
+    }
+
+    // Compare the running prize totals of a hackathon with a recount from its awards
+    pub fn get_prize_totals_check(&self, hackathon_id: HackathonId) -> Option<PrizeTotalsCheck> {
+        let hackathon = self.get_hackathon_by_id(hackathon_id)?;
+        let computed = self.count_prize_totals(&hackathon);
+        let consistent = computed.len() == hackathon.prizes.len()
+            && computed
+                .iter()
+                .all(|totals| hackathon.prizes.contains(totals));
+
+        Some(PrizeTotalsCheck {
+            cached: hackathon.prizes,
+            computed,
+            consistent,
+        })
+    }
+
+    pub fn get_hackathon_categories(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<CategoryJson> {
+        let hackathon = match self.hackathons.get(&hackathon_id) {
+            Some(hackathon) => hackathon,
+            None => return Page::from_vec(Vec::new(), from_index, limit),
+        };
+        let categories = hackathon.categories_list.as_vector();
+        let total = categories.len();
+
+        Page {
+            items: page_range(total, from_index, limit)
+                .filter_map(|index| categories.get(index))
+                .filter_map(|category_id| self.get_category_by_id(category_id))
+                .collect(),
+            total,
+        }
+    }
+
+    // Every field of one hackathon, the list views only carry its summary
+    pub fn get_hackathon(&self, hackathon_id: HackathonId) -> Option<HackathonWithTotalPrize> {
+        let hackathon = self.get_hackathon_by_id(hackathon_id)?;
+        let total_prize = hackathon.total_prize();
+
+        Some(HackathonWithTotalPrize {
+            hackathon: hackathon.view(),
+            total_prize,
+        })
+    }
+
+    pub fn get_hackathon_summary(&self, hackathon_id: HackathonId) -> Option<HackathonSummary> {
+        let hackathon = self.get_hackathon_by_id(hackathon_id)?;
+
+        Some(hackathon.summary())
+    }
+
+    pub fn get_hackathon_summaries(
+        &self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<HackathonSummary> {
+        let hackathons = self.hackathons_list.as_vector();
+        let total = hackathons.len();
+
+        Page {
+            items: page_range(total, from_index, limit)
+                .filter_map(|index| hackathons.get(index))
+                .filter_map(|hackathon_id| self.get_hackathon_summary(hackathon_id))
+                .collect(),
+            total,
+        }
+    }
+
+    // Loads every list of the hackathon at once, big hackathons should use the summary and the
+    // paged participant, submission and category views instead
+    pub fn get_detail_hackathon_by_id(&self, hackathon_id: HackathonId) -> Option<HackathonJson> {
+        let hackathon = self.hackathons.get(&hackathon_id)?;
+        let pars = hackathon
+            .participants_list
+            .iter()
+            .filter_map(|p_id| self.get_member_by_id(p_id))
+            .collect();
+        let cats = hackathon
+            .categories_list
+            .iter()
+            .filter_map(|c_id| self.get_category_by_id(c_id))
+            .collect();
+        let subs = hackathon
+            .submissions_list
+            .iter()
+            .filter_map(|s_id| self.get_submission_by_id(s_id))
+            .collect();
+
+        Some(HackathonJson {
+            participants: pars,
+            submissions: subs,
+            categories: cats,
+        })
+    }
+
+    pub fn get_hackathon_edits(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<Edit> {
+        Page::from_vec(
+            self.edits.get(&hackathon_id).unwrap_or_default(),
+            from_index,
+            limit,
+        )
+    }
+
+    pub fn get_contract_owner(&self) -> AccountId {
+        self.owner_id.clone()
+    }
+
+    pub fn get_upgrade_delay(&self) -> U64 {
+        U64(self.upgrade_delay)
+    }
+
+    pub fn get_staged_upgrade(&self) -> Option<StagedUpgrade> {
+        self.staged_upgrade.clone()
+    }
+
+    pub fn get_state_version(&self) -> StateVersion {
+        StateVersion {
+            version: self.state_version,
+            migration: self.migration,
+        }
+    }
+
+    // NEP-330, `version` is the version of the ABI the frontends should be built against
+    pub fn contract_source_metadata(&self) -> ContractSourceMetadata {
+        let standard = |standard: &str, version: &str| Standard {
+            standard: standard.to_string(),
+            version: version.to_string(),
+        };
+        ContractSourceMetadata {
+            version: CONTRACT_VERSION.to_string(),
+            link: None,
+            standards: vec![
+                standard("nep145", "1.0.0"),
+                standard("nep297", "1.0.0"),
+                standard("nep330", "1.1.0"),
+            ],
+        }
+    }
+
+    pub fn get_team(&self, team_id: TeamId) -> Option<Team> {
+        self.teams.get(&team_id)
+    }
+
+    pub fn get_hackathon_teams(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<Team> {
+        let teams = self
+            .hackathons
+            .get(&hackathon_id)
+            .map(|hackathon| hackathon.teams_list)
+            .unwrap_or_default();
+        let total = teams.len() as u64;
+
+        Page {
+            items: teams[page_slice(total, from_index, limit)]
+                .iter()
+                .filter_map(|team_id| self.teams.get(team_id))
+                .collect(),
+            total,
+        }
+    }
+
+    pub fn get_member_invitations(
+        &self,
+        account_id: AccountId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<Team> {
+        let invitations = self
+            .members
+            .get(&account_id)
+            .map(|member| member.invitations)
+            .unwrap_or_default();
+        let total = invitations.len() as u64;
+
+        Page {
+            items: invitations[page_slice(total, from_index, limit)]
+                .iter()
+                .filter_map(|team_id| self.teams.get(team_id))
+                .collect(),
+            total,
+        }
+    }
+
+    pub fn get_posts(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<Post> {
+        Page::from_vec(self.get_open_posts(hackathon_id), from_index, limit)
+    }
+
+    // Pairs every "looking for team" post with every open team, best skill overlap first
+    pub fn get_post_matches(
+        &self,
+        hackathon_id: HackathonId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<PostMatch> {
+        let posts = self.get_open_posts(hackathon_id);
+        let mut matches = Vec::new();
+
+        for solo in posts.iter().filter(|p| p.team_id.is_none()) {
+            for team in posts.iter().filter(|p| p.team_id.is_some()) {
+                let skills = solo.overlap(team);
+                if !skills.is_empty() {
+                    matches.push(PostMatch {
+                        account_id: solo.author.clone(),
+                        team_id: team.team_id.unwrap(),
+                        score: skills.len() as u32,
+                        skills,
+                    });
+                }
+            }
+        }
+
+        matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+        Page::from_vec(matches, from_index, limit)
+    }
+
+    pub fn get_submission_revision(
+        &self,
+        submission_id: SubmissionId,
+        revision: u32,
+    ) -> Option<SubmissionRevision> {
+        self.submission_revisions.get(&(submission_id, revision))
+    }
+
+    pub fn get_submission_revisions(
+        &self,
+        submission_id: SubmissionId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<SubmissionRevision> {
+        let total = self
+            .submissions
+            .get(&submission_id)
+            .map_or(0, |submission| submission.revisions as u64);
+
+        Page {
+            items: page_range(total, from_index, limit)
+                .filter_map(|number| {
+                    self.submission_revisions
+                        .get(&(submission_id, number as u32))
+                })
+                .collect(),
+            total,
+        }
+    }
+
+    // The last revision written before the hackathon's deadline, which is what judges review
+    pub fn get_deadline_revision(&self, submission_id: SubmissionId) -> Option<SubmissionRevision> {
+        let submission = self.submissions.get(&submission_id)?;
+        let team = self.teams.get(&submission.team_id)?;
+        let hackathon = self.hackathons.get(&team.hackathon_id)?;
+
+        (0..submission.revisions)
+            .rev()
+            .filter_map(|number| self.submission_revisions.get(&(submission_id, number)))
+            .find(|revision| revision.time < hackathon.end)
+    }
+
+    pub fn get_user_information_by_id(&self, id: AccountId) -> Option<MemberJsonDetail> {
+        match self.members.get(&id) {
+            Some(user) => {
+                let c_hack = user
+                    .created_hackathons
+                    .into_iter()
+                    .filter_map(|hackathon| self.get_hackathon(hackathon))
+                    .collect();
+                let p_hack = user
+                    .joined_hackathons
+                    .into_iter()
+                    .filter_map(|hackathon| self.get_hackathon(hackathon))
+                    .collect();
+
+                Some(MemberJsonDetail {
+                    id: user.id,
+                    name: user.name,
+                    image: user.image,
+                    bio: user.bio,
+                    joined_hackathons: p_hack,
+                    created_hackathons: c_hack,
+                })
+            }
+            None => None,
+        }
+    }
+}
+
+// PRIVATE VIEW FUNCTION
+#[near_bindgen]
+impl HackathonContract {
+    fn assert_contract_owner(&self) {
+        assert_eq!(env::predecessor_account_id(), self.owner_id, "Not owner");
+    }
+
+    // Validate the hackathon -> category -> award chain and return the award
+    fn award_in_hackathon(
+        &self,
+        hackathon: &Hackathon,
+        category_id: CategoryId,
+        award_id: AwardId,
+    ) -> Award {
+        assert!(
+            hackathon.categories_list.contains(&category_id),
+            "category is not in hackathon"
+        );
+
+        let category = self
+            .categories
+            .get(&category_id)
+            .expect("Category not exist");
+        assert!(
+            category.awards.contains(&award_id),
+            "award is not in category"
+        );
+
+        self.awards.get(&award_id).expect("Award not exist")
+    }
+
+    // Prizes are paid to the first member of the winning submission
+    fn winner_receiver(&self, submission_id: SubmissionId) -> AccountId {
+        self.submissions
+            .get(&submission_id)
+            .and_then(|sub| sub.members.first().cloned())
+            .expect("Submission not exist")
+    }
+
+    // The awards of a hackathon the submission has been judged the winner of
+    fn awards_won(
+        &self,
+        hackathon: &Hackathon,
+        submission_id: SubmissionId,
+    ) -> Vec<(AwardId, Award)> {
+        let mut won = Vec::new();
+        for category_id in hackathon.categories_list.iter() {
+            if let Some(category) = self.categories.get(&category_id) {
+                for award_id in category.awards.iter() {
+                    if let Some(award) = self.awards.get(award_id) {
+                        if award.winner == Some(submission_id) {
+                            won.push((*award_id, award));
+                        }
+                    }
+                }
+            }
+        }
+        won
+    }
+
+    // The members of a team can not change while the DAO votes on a prize of its submissions
+    fn assert_no_pending_payout(&self, hackathon: &Hackathon, team: &Team) {
+        for submission_id in team.submissions.iter() {
+            for (_, award) in self.awards_won(hackathon, *submission_id) {
+                assert!(award.payout.is_none(), "Payout proposed to the DAO");
+            }
+        }
+    }
+
+    fn assert_categories_in_hackathon(&self, hackathon: &Hackathon, categories: &[CategoryId]) {
+        for (index, category_id) in categories.iter().enumerate() {
+            assert!(
+                hackathon.categories_list.contains(category_id),
+                "category is not in hackathon"
+            );
+            assert!(
+                !categories[..index].contains(category_id),
+                "Duplicate category"
+            );
+        }
+    }
+
+    fn team_in_hackathon(&self, member: &Member, hackathon_id: HackathonId) -> Option<TeamId> {
+        member.teams.iter().copied().find(|team_id| {
+            self.teams
+                .get(team_id)
+                .is_some_and(|team| team.hackathon_id == hackathon_id)
+        })
+    }
+
+    fn get_hackathon_by_id(&self, hackathon_id: HackathonId) -> Option<Hackathon> {
+        self.hackathons.get(&hackathon_id)
+    }
+
+    fn get_hackathons_page(
+        &self,
+        hackathon_ids: Vec<HackathonId>,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Page<HackathonSummary> {
+        let total = hackathon_ids.len() as u64;
+
+        Page {
+            items: hackathon_ids[page_slice(total, from_index, limit)]
+                .iter()
+                .filter_map(|hackathon_id| self.get_hackathon_summary(*hackathon_id))
+                .collect(),
+            total,
+        }
+    }
+
+    // Hackathons that started by `now` after the cursor, earliest first. Cancelled ones are not
+    // indexed. The range starts at the lowest key, `TreeMap::range` yields nothing from an
+    // unbounded start
+    fn get_started_hackathons(
+        &self,
+        now: Timestamp,
+        after: Option<CalendarCursor>,
+    ) -> impl Iterator<Item = (CalendarCursor, HackathonId)> + '_ {
+        let last = (now, HackathonId::MAX);
+        let lower = after.map_or(Bound::Included((0, 0)), |cursor| {
+            Bound::Excluded(cursor.start_key().min(last))
+        });
+
+        self.hackathons_by_start
+            .range((lower, Bound::Included(last)))
+            .map(start_entry)
+    }
+
+    // Hackathons after the cursor, the largest total prize first and the oldest first between
+    // equal prizes
+    fn get_prize_ordered_hackathons(
+        &self,
+        after: Option<CalendarCursor>,
+    ) -> impl Iterator<Item = (CalendarCursor, HackathonId)> + '_ {
+        let lower = after.map_or(Bound::Included((0, 0)), |cursor| {
+            Bound::Excluded(cursor.prize_key())
+        });
+
+        self.hackathons_by_prize
+            .range((lower, Bound::Unbounded))
+            .map(|((key, hackathon_id), _)| {
+                let prize = U128(u128::MAX - key);
+                (CalendarCursor::Prize(prize, hackathon_id), hackathon_id)
+            })
+    }
+
+    // Page the hackathons the filter keeps, in the order given. The cursor is the index key of the
+    // last hackathon read, which may be one the filter dropped
+    fn get_calendar_page<I, F>(
+        &self,
+        hackathons: I,
+        filter: F,
+        limit: Option<u64>,
+    ) -> CursorPage<HackathonSummary, CalendarCursor>
+    where
+        I: Iterator<Item = (CalendarCursor, HackathonId)>,
+        F: Fn(&Hackathon) -> bool,
+    {
+        let hackathons = hackathons.map(|(cursor, hackathon_id)| {
+            let summary = self
+                .get_hackathon_by_id(hackathon_id)
+                .filter(|hackathon| filter(hackathon))
+                .map(|hackathon| hackathon.summary());
+
+            (cursor, summary)
+        });
+
+        scan_page(hackathons, limit)
+    }
+
+    fn get_open_posts(&self, hackathon_id: HackathonId) -> Vec<Post> {
+        match self.hackathons.get(&hackathon_id) {
+            Some(hackathon) if hackathon.phase() != HackathonPhase::Ended => {
+                self.posts.get(&hackathon_id).unwrap_or_default()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn get_category_by_id(&self, category_id: CategoryId) -> Option<CategoryJson> {
+        if let Some(result) = self.categories.get(&category_id) {
+            // let mut prizes: Vec<String> = Vec::new();
+            let mut awards = Vec::new();
+
+            for i in result.awards.iter() {
+                // let prize = self.get_prize_by_id(*i).unwrap();
+                // prizes.push(prize);
+                if let Some(a) = self.get_award_by_id(*i) {
+                    awards.push(a);
+                }
+            }
+
+            let category_json: CategoryJson = CategoryJson {
+                name: result.name,
+                id: result.id,
+                awards, // prizes_list: prizes
+            };
+            Some(category_json)
+        } else {
+            None
+        }
+    }
+
+    fn get_award_by_id(&self, award_id: AwardId) -> Option<AwardJson> {
+        if let Some(rs) = self.awards.get(&award_id) {
+            match rs.winner {
+                Some(id) => {
+                    let s_json = self.get_submission_by_id(id);
+                    let a_json = AwardJson {
+                        name: rs.name,
+                        id: rs.id,
+                        price: rs.price,
+                        funded: rs.funded,
+                        winner: s_json,
+                        is_awarded: rs.is_awarded,
+                        payout: rs.payout,
+                    };
+                    Some(a_json)
+                }
+                None => Some(AwardJson {
+                    name: rs.name,
+                    id: rs.id,
+                    price: rs.price,
+                    funded: rs.funded,
+                    winner: None,
+                    is_awarded: rs.is_awarded,
+                    payout: rs.payout,
+                }),
+            }
+        } else {
+            None
+        }
+    }
+
+    fn get_member_by_id(&self, member_id: AccountId) -> Option<MemberJson> {
+        if let Some(result) = self.members.get(&member_id) {
+            Some(MemberJson {
+                name: result.name,
+                id: result.id,
+                image: result.image,
+                bio: result.bio,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn get_submission_by_id(&self, submission_id: SubmissionId) -> Option<SubmissionJson> {
+        if let Some(result) = self.submissions.get(&submission_id) {
+            let mut pars = Vec::new();
+            let mut cats = Vec::new();
+
+            for p_id in result.categories.iter() {
+                // let cat = self.get_category_by_id(*p_id).unwrap();
+                if let Some(cat) = self.categories.get(p_id) {
+                    cats.push(cat);
+                }
+            }
+
+            for p_id in result.members.iter() {
+                if let Some(par) = self.get_member_by_id(p_id.clone()) {
+                    pars.push(par);
+                }
+            }
+
+            Some(SubmissionJson {
+                link: result.link(),
+                id: result.id,
+                team_id: result.team_id,
+                categories: cats,
+                members: pars,
+                name: result.name,
+                artifacts: result.artifacts,
+                image: result.image,
+                description: result.description,
+                time: result.time,
+                content: result.content,
+                commit: result.commit,
+                revisions: result.revisions,
+            })
+        } else {
+            None
+        }
+    }
+
+    // Count the prize totals from the awards, the hackathon keeps a running copy of them
+    fn count_prize_totals(&self, hackathon: &Hackathon) -> Vec<PrizeTotals> {
+        let mut totals: Vec<PrizeTotals> = Vec::new();
 
         for category_id in hackathon.categories_list.iter() {
-            match self.categories.get(category_id) {
-                Some(category) => {
-                    for award_id in category.awards.iter() {
-                        match self.awards.get(award_id) {
-                            Some(award) => {
-                                let price: Balance = award.price.into();
-                                total += price;
-                            }
-                            None => (),
+            if let Some(category) = self.categories.get(&category_id) {
+                for award_id in category.awards.iter() {
+                    if let Some(award) = self.awards.get(award_id) {
+                        let counted = token_totals(&mut totals, award.token());
+                        counted.promised.0 += award.price.0;
+                        counted.funded.0 += award.funded.0;
+                        if award.is_awarded {
+                            counted.paid.0 += award.price.0;
                         }
                     }
                 }
-                None => (),
             }
         }
 
-        U128(total)
+        totals
+    }
+}
+
+// PRIVATE CALL FUNCTION
+impl HackathonContract {
+    fn index_hackathon(&mut self, hackathon: &Hackathon) {
+        self.index_tags(hackathon.id, &hackathon.tags);
+        self.hackathons_by_start
+            .insert(&(hackathon.start, hackathon.id), &());
+        self.hackathons_by_prize.insert(&hackathon.prize_key(), &());
+    }
+
+    fn unindex_hackathon(&mut self, hackathon: &Hackathon) {
+        self.unindex_tags(hackathon.id, &hackathon.tags);
+        self.hackathons_by_start
+            .remove(&(hackathon.start, hackathon.id));
+        self.hackathons_by_prize.remove(&hackathon.prize_key());
+    }
+
+    // Move a hackathon in the prize index after its total prize changed from the one in `old_key`
+    fn reindex_prize(&mut self, hackathon: &Hackathon, old_key: (u128, HackathonId)) {
+        self.hackathons_by_prize.remove(&old_key);
+        if !hackathon.cancelled {
+            self.hackathons_by_prize.insert(&hackathon.prize_key(), &());
+        }
+    }
+
+    // Every tag gets its own set, keyed by the tag hash so long tags stay within the key limit
+    fn index_tags(&mut self, hackathon_id: HackathonId, tags: &[String]) {
+        for tag in tags {
+            let mut hackathon_ids = self.hackathons_by_tag.get(tag).unwrap_or_else(|| {
+                UnorderedSet::new(StorageKey::TagHackathons {
+                    tag_hash: env::sha256_array(tag.as_bytes()),
+                })
+            });
+            hackathon_ids.insert(&hackathon_id);
+            self.hackathons_by_tag.insert(tag, &hackathon_ids);
+        }
+    }
+
+    fn unindex_tags(&mut self, hackathon_id: HackathonId, tags: &[String]) {
+        for tag in tags {
+            if let Some(mut hackathon_ids) = self.hackathons_by_tag.get(tag) {
+                hackathon_ids.remove(&hackathon_id);
+                if hackathon_ids.is_empty() {
+                    self.hackathons_by_tag.remove(tag);
+                } else {
+                    self.hackathons_by_tag.insert(tag, &hackathon_ids);
+                }
+            }
+        }
+    }
+
+    // Deploy `code` and migrate the state with it. Without a timelock the code may be deployed
+    // directly, otherwise it has to match a staged upgrade whose delay ran out
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    fn deploy_upgrade(&self, code: Vec<u8>) -> Promise {
+        self.assert_contract_owner();
+
+        let code_hash = Base58CryptoHash::from(
+            <[u8; 32]>::try_from(env::sha256(&code)).expect("sha256 is 32 bytes"),
+        );
+        match self.staged_upgrade.as_ref() {
+            Some(staged) => {
+                assert!(
+                    staged.code_hash == code_hash,
+                    "Code does not match the staged upgrade"
+                );
+                assert!(
+                    env::block_timestamp() >= staged.deployable_at,
+                    "Upgrade is timelocked until {}",
+                    staged.deployable_at
+                );
+            }
+            None => assert_eq!(self.upgrade_delay, 0, "Upgrade must be staged first"),
+        }
+
+        let gas = env::prepaid_gas()
+            .0
+            .saturating_sub(env::used_gas().0 + GAS_FOR_UPGRADE.0 + GAS_FOR_UPGRADE_CALLBACK.0);
+        Promise::new(env::current_account_id())
+            .deploy_contract(code)
+            .function_call("migrate".to_string(), b"{}".to_vec(), 0, Gas(gas))
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_UPGRADE_CALLBACK)
+                    .on_upgrade_deployed(code_hash),
+            )
+    }
+
+    fn from_v0(old: HackathonContractV0) -> Self {
+        let mut contract = Self::init(Some(env::current_account_id()), None);
+        contract.members = old.members;
+        contract.awards = old.awards;
+        contract.hackathons = old.hackathons;
+        contract.submissions = old.submissions;
+        contract.categories = old.categories;
+        contract.next_submission_id = old.next_submission_id;
+        contract.next_category_id = old.next_category_id;
+        contract.next_award_id = old.next_award_id;
+        contract.next_hackathon_id = old.next_hackathon_id;
+        contract.state_version = 0;
+        contract.migration = Some(MigrationCursor {
+            stage: MigrationStage::Hackathons,
+            next_id: 0,
+        });
+        contract
+    }
+
+    // Convert up to `limit` entities from where the last call stopped. Members are not
+    // enumerable here and are upgraded when they are next written.
+    fn migrate_batch(&mut self, limit: u64) {
+        let mut converted = 0;
+
+        while let Some(cursor) = self.migration {
+            let pending = match cursor.stage {
+                MigrationStage::Hackathons => cursor.next_id < self.next_hackathon_id,
+                MigrationStage::Categories => cursor.next_id < self.next_category_id,
+                MigrationStage::Awards => cursor.next_id < self.next_award_id,
+                MigrationStage::MembersList => {
+                    env::storage_has_key(&legacy_list_slot(cursor.next_id))
+                }
+            };
+            if !pending {
+                self.migration = cursor.next_stage();
+                continue;
+            }
+            if converted == limit {
+                return;
+            }
+
+            match cursor.stage {
+                MigrationStage::Hackathons => self.migrate_hackathon(cursor.next_id),
+                MigrationStage::Categories => self.categories.upgrade(&cursor.next_id),
+                MigrationStage::Awards => self.awards.upgrade(&cursor.next_id),
+                MigrationStage::MembersList => self.repair_members_list_slot(cursor.next_id),
+            }
+            self.migration = Some(MigrationCursor {
+                next_id: cursor.next_id + 1,
+                ..cursor
+            });
+            converted += 1;
+        }
+    }
+
+    // Add a hackathon and every member it references to the separated lists
+    fn repair_hackathons_list(&mut self, hackathon: &Hackathon) {
+        env::storage_remove(&legacy_list_index(&hackathon.id.to_le_bytes()));
+        self.hackathons_list.insert(&hackathon.id);
+
+        let mut account_ids = vec![hackathon.owner.clone()];
+        account_ids.extend(hackathon.organizers.iter().cloned());
+        account_ids.extend(hackathon.participants_list.iter());
+        for team_id in hackathon.teams_list.iter() {
+            if let Some(team) = self.teams.get(team_id) {
+                account_ids.extend(team.members);
+            }
+        }
+        self.repair_members(account_ids);
+    }
+
+    // A slot of the shared list holds an account id, unless a hackathon id overwrote it
+    fn repair_members_list_slot(&mut self, index: u64) {
+        let slot = legacy_list_slot(index);
+        let element = env::storage_read(&slot).unwrap_or_default();
+        env::storage_remove(&slot);
+        env::storage_remove(&legacy_list_index(&element));
+
+        if let Ok(account_id) = AccountId::try_from_slice(&element) {
+            self.repair_members(vec![account_id]);
+        }
+    }
+
+    fn repair_members(&mut self, account_ids: Vec<AccountId>) {
+        for account_id in account_ids {
+            env::storage_remove(&legacy_list_index(&account_id.try_to_vec().unwrap()));
+            if self.members.contains_key(&account_id) {
+                self.members_list.insert(&account_id);
+            }
+        }
+    }
+
+    // Every submission of an unversioned hackathon becomes the submission of a team of its members.
+    // The migrated hackathon is listed, its prize totals counted and it is added to the indexes
+    fn migrate_hackathon(&mut self, hackathon_id: HackathonId) {
+        let legacy = match self.hackathons.get_legacy(&hackathon_id) {
+            Some(legacy) => legacy,
+            None => return,
+        };
+        let mut teams_list = Vec::new();
+
+        for submission_id in legacy.submissions_list.iter() {
+            let submission = match self.submissions.get_legacy(submission_id) {
+                Some(submission) => submission,
+                None => continue,
+            };
+            let team_id = self.next_team_id;
+            self.next_team_id += 1;
+
+            let team = Team::for_submission(
+                team_id,
+                hackathon_id,
+                submission.name.clone(),
+                submission.members.clone(),
+                *submission_id,
+            );
+            for account_id in team.members.iter() {
+                if let Some(mut member) = self.members.get(account_id) {
+                    member.teams.push(team_id);
+                    self.members.insert(account_id, &member);
+                }
+            }
+
+            self.teams.insert(&team_id, &team);
+            self.submissions
+                .insert(submission_id, &submission.migrate(team_id));
+            teams_list.push(team_id);
+        }
+
+        let mut hackathon = legacy.migrate(teams_list);
+        hackathon.prizes = self.count_prize_totals(&hackathon);
+        self.hackathons.insert(&hackathon_id, &hackathon);
+        self.repair_hackathons_list(&hackathon);
+        self.index_hackathon(&hackathon);
+    }
+
+    // Bill the bytes a call added to the caller's storage balance. Teams, submissions, revisions
+    // and posts are billed to their payer, and only their payer is credited when they are freed.
+    // Other freed bytes are not credited, the caller may not be the one who paid for them
+    fn charge_storage(&mut self, initial_storage: StorageUsage) {
+        // Every call that writes ends here, and a panic reverts its writes
+        assert!(self.migration.is_none(), "Contract state is being migrated");
+
+        let added = env::storage_usage() as i64 - initial_storage as i64 - self.billed_to_payers;
+        self.billed_to_payers = 0;
+
+        if added > 0 {
+            let account_id = env::predecessor_account_id();
+            let mut account = self
+                .storage_accounts
+                .get(&account_id)
+                .expect("Storage not registered, call storage_deposit first");
+            account.used_bytes += added as StorageUsage;
+            assert!(
+                account.used() <= account.total,
+                "Insufficient storage balance: {} yoctoNEAR required, {} deposited",
+                account.used(),
+                account.total
+            );
+            self.storage_accounts.insert(&account_id, &account);
+        }
+    }
+
+    // Run a write to an entity and bill its payer for the bytes it added or freed. The bytes of
+    // an entity without a payer are left to the caller
+    fn write_paid<F: FnOnce(&mut Self)>(&mut self, payer: Option<&AccountId>, write: F) {
+        let initial_storage = env::storage_usage();
+        write(self);
+
+        if let Some(payer) = payer {
+            let change = env::storage_usage() as i64 - initial_storage as i64;
+            self.bill_storage(payer, change);
+        }
+    }
+
+    // Add bytes to the usage of the payer of an entity, or credit them back when negative. The
+    // payer was billed for every byte of the entity, so a credit only returns what they paid
+    fn bill_storage(&mut self, payer: &AccountId, change: i64) {
+        let mut account = match self.storage_accounts.get(payer) {
+            Some(account) if change != 0 => account,
+            _ => return,
+        };
+
+        let change = if change > 0 {
+            account.used_bytes += change as StorageUsage;
+            assert!(
+                account.used() <= account.total,
+                "Insufficient storage balance of {}: {} yoctoNEAR required, {} deposited",
+                payer,
+                account.used(),
+                account.total
+            );
+            change
+        } else {
+            let credit = change.unsigned_abs().min(account.used_bytes);
+            account.used_bytes -= credit;
+            -(credit as i64)
+        };
+        self.storage_accounts.insert(payer, &account);
+        self.billed_to_payers += change;
+    }
+
+    fn insert_team(&mut self, team: &Team) {
+        self.write_paid(team.payer.as_ref(), |contract| {
+            contract.teams.insert(&team.id, team)
+        });
+    }
+
+    fn insert_submission(&mut self, submission: &Submission) {
+        self.write_paid(submission.payer.as_ref(), |contract| {
+            contract.submissions.insert(&submission.id, submission)
+        });
+    }
+
+    fn insert_revision(&mut self, submission_id: SubmissionId, revision: &SubmissionRevision) {
+        self.write_paid(Some(&revision.editor), |contract| {
+            contract
+                .submission_revisions
+                .insert(&(submission_id, revision.number), revision);
+        });
+    }
+
+    fn record_edits(&mut self, hackathon_id: HackathonId, edits: Vec<Edit>) {
+        if edits.is_empty() {
+            return;
+        }
+
+        let mut history = self.edits.get(&hackathon_id).unwrap_or_default();
+        history.extend(edits);
+        self.edits.insert(&hackathon_id, &history);
+    }
+
+    // Apply `f` to the board of a hackathon, dropping the board once the hackathon ended
+    fn update_posts<F: FnOnce(&mut Vec<Post>)>(&mut self, hackathon: &Hackathon, f: F) {
+        let old_posts = self.posts.get(&hackathon.id).unwrap_or_default();
+        let mut posts = old_posts.clone();
+        if hackathon.phase() == HackathonPhase::Ended {
+            posts.clear();
+        } else {
+            f(&mut posts);
+        }
+
+        // Every post is paid for by its author, so each one is written on its own to measure it
+        let mut board = old_posts.clone();
+        for post in old_posts.iter().filter(|post| !posts.contains(post)) {
+            board.retain(|p| p != post);
+            self.write_paid(Some(&post.author), |contract| {
+                contract.write_board(hackathon.id, &board)
+            });
+            HackathonEvent::PostRemoved {
+                hackathon_id: hackathon.id,
+                author: post.author.clone(),
+                team_id: post.team_id,
+            }
+            .emit();
+        }
+        for post in posts.iter().filter(|post| !old_posts.contains(post)) {
+            board.push(post.clone());
+            self.write_paid(Some(&post.author), |contract| {
+                contract.write_board(hackathon.id, &board)
+            });
+            HackathonEvent::PostAdded {
+                hackathon_id: hackathon.id,
+                author: post.author.clone(),
+                team_id: post.team_id,
+            }
+            .emit();
+        }
+    }
+
+    fn write_board(&mut self, hackathon_id: HackathonId, posts: &Vec<Post>) {
+        if posts.is_empty() {
+            self.posts.remove(&hackathon_id);
+        } else {
+            self.posts.insert(&hackathon_id, posts);
+        }
+    }
+
+    // Move the storage of a team and of the submissions a leaving member paid for to the leader
+    // of the team, the leaving member is credited and the leader billed
+    fn hand_over_storage(&mut self, team: &mut Team, account_id: &AccountId) {
+        if team.payer.as_ref() == Some(account_id) {
+            self.write_paid(Some(account_id), |contract| contract.teams.remove(&team.id));
+            team.payer = Some(team.leader.clone());
+        }
+
+        for submission_id in team.submissions.iter() {
+            if let Some(mut submission) = self.submissions.get(submission_id) {
+                if submission.payer.as_ref() == Some(account_id) {
+                    self.write_paid(Some(account_id), |contract| {
+                        contract.submissions.remove(submission_id)
+                    });
+                    submission.payer = Some(team.leader.clone());
+                    self.insert_submission(&submission);
+                }
+            }
+        }
+    }
+
+    // Keep the member list of a team's submissions equal to the team itself and return the
+    // submissions that changed. A winning submission keeps the members it was judged with, its
+    // prize goes to the first of them
+    fn sync_team_submissions(&mut self, hackathon: &Hackathon, team: &Team) -> Vec<SubmissionId> {
+        let mut synced = Vec::new();
+        for submission_id in team.submissions.iter() {
+            if !self.awards_won(hackathon, *submission_id).is_empty() {
+                continue;
+            }
+            if let Some(mut submission) = self.submissions.get(submission_id) {
+                submission.members = team.members.clone();
+                self.insert_submission(&submission);
+                synced.push(*submission_id);
+            }
+        }
+
+        synced
     }
+}
+
+// Round to micro-NEAR first, multiplying the float by 10^24 directly loses precision (1.0 -> 0.99..)
+fn near_to_yocto(total: f64) -> Balance {
+    (total * 1_000_000.0).round() as Balance * 1_000_000_000_000_000_000
+}
+
+// An entry of the start index with its calendar cursor
+fn start_entry(
+    ((start, hackathon_id), _): ((Timestamp, HackathonId), ()),
+) -> (CalendarCursor, HackathonId) {
+    (CalendarCursor::Start(start, hackathon_id), hackathon_id)
 }
\ No newline at end of file
@@ -4,6 +4,8 @@ use near_sdk::AccountId;
 use near_sdk::serde::{Serialize, Deserialize};
 
 use crate::hackathon::{HackathonId, HackathonWithTotalPrize};
+use crate::team::TeamId;
+use crate::version::Versioned;
 
 // Define the Member structure
 #[derive(BorshDeserialize, BorshSerialize,Serialize, Deserialize, Debug)]
@@ -14,7 +16,9 @@ pub struct Member {
     pub image: Option<String>,
     pub bio: Option<String>,
     pub joined_hackathons: Vec<HackathonId>,
-    pub created_hackathons: Vec<HackathonId>
+    pub created_hackathons: Vec<HackathonId>,
+    pub teams: Vec<TeamId>,
+    pub invitations: Vec<TeamId>
 }
 
 // Implement the Member structure
@@ -27,12 +31,15 @@ impl Member {
             bio,
             joined_hackathons: Vec::new(),
             created_hackathons: Vec::new(),
+            teams: Vec::new(),
+            invitations: Vec::new(),
         }
     }
 }
 
 // Define the Member Json structure
 #[derive(BorshDeserialize, BorshSerialize,Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
 #[serde(crate = "near_sdk::serde")]
 pub struct MemberJson {
     pub id: AccountId,
@@ -42,7 +49,8 @@ pub struct MemberJson {
 }
 
 // Define the Detail Member Json structure - include information of joined and created hackathons
-#[derive(BorshDeserialize, BorshSerialize,Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
 #[serde(crate = "near_sdk::serde")]
 pub struct MemberJsonDetail {
     pub id: AccountId,
@@ -51,4 +59,49 @@ pub struct MemberJsonDetail {
     pub bio: Option<String>,
     pub joined_hackathons: Vec<HackathonWithTotalPrize>,
     pub created_hackathons: Vec<HackathonWithTotalPrize>
+}
+
+// Define the member layout before state versioning
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct MemberV0 {
+    pub id: AccountId,
+    pub name: String,
+    pub image: Option<String>,
+    pub bio: Option<String>,
+    pub joined_hackathons: Vec<HackathonId>,
+    pub created_hackathons: Vec<HackathonId>
+}
+
+// Define the versioned member, as it is stored
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum VersionedMember {
+    V0(MemberV0),
+    V1(Member),
+}
+
+// Implement the versioned member - older members have no teams or invitations yet
+impl Versioned for VersionedMember {
+    type Entity = Member;
+    type Legacy = MemberV0;
+    const CURRENT: u8 = 1;
+
+    fn from_legacy(legacy: MemberV0) -> Self {
+        VersionedMember::V0(legacy)
+    }
+
+    fn into_current(self) -> Member {
+        match self {
+            VersionedMember::V0(member) => Member {
+                id: member.id,
+                name: member.name,
+                image: member.image,
+                bio: member.bio,
+                joined_hackathons: member.joined_hackathons,
+                created_hackathons: member.created_hackathons,
+                teams: Vec::new(),
+                invitations: Vec::new(),
+            },
+            VersionedMember::V1(member) => member,
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,119 @@
+use near_sdk::serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+pub const DEFAULT_PAGE_LIMIT: u64 = 50;
+pub const MAX_PAGE_LIMIT: u64 = 100;
+// Entries a filtered view reads at most, a sparse filter returns a short page and the cursor
+pub const MAX_SCAN: u64 = 200;
+
+// Define the Page structure (response) - one slice of a list view and the size of the whole list
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+}
+
+// Define the CursorPage structure (response) - one slice of an ordered index and the key to resume
+// after, `None` once the index is exhausted
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct CursorPage<T, K> {
+    pub items: Vec<T>,
+    pub next: Option<K>,
+}
+
+// Implement the Page structure
+impl<T> Page<T> {
+    // Page through a list that is already in memory
+    pub fn from_vec(items: Vec<T>, from_index: Option<u64>, limit: Option<u64>) -> Self {
+        let total = items.len() as u64;
+        let range = page_slice(total, from_index, limit);
+
+        Page {
+            items: items
+                .into_iter()
+                .take(range.end)
+                .skip(range.start)
+                .collect(),
+            total,
+        }
+    }
+}
+
+// The indexes a page covers, with the limit capped so a view stays within gas
+pub fn page_range(total: u64, from_index: Option<u64>, limit: Option<u64>) -> Range<u64> {
+    let start = from_index.unwrap_or(0).min(total);
+
+    start..start.saturating_add(page_limit(limit)).min(total)
+}
+
+// The requested page size, capped so a view stays within gas
+pub fn page_limit(limit: Option<u64>) -> u64 {
+    limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT)
+}
+
+// Page through the entries of an ordered index, yielding `limit` of them and the key of the last
+// one as the cursor when more follow. An empty page could never move the cursor, so it holds at
+// least one
+pub fn cursor_page<K, T>(
+    entries: impl Iterator<Item = (K, T)>,
+    limit: Option<u64>,
+) -> CursorPage<T, K> {
+    let limit = page_limit(limit).max(1) as usize;
+    let mut entries: Vec<(K, T)> = entries.take(limit + 1).collect();
+    let more = entries.len() > limit;
+    entries.truncate(limit);
+
+    let mut items = Vec::with_capacity(entries.len());
+    let mut next = None;
+    for (key, item) in entries {
+        items.push(item);
+        next = Some(key);
+    }
+
+    CursorPage {
+        items,
+        next: next.filter(|_| more),
+    }
+}
+
+// Page through the entries of an ordered index a filter thins out, an entry it dropped has no
+// item. At most `MAX_SCAN` entries are read, the cursor is the key of the last one read, so a page
+// may come back short or empty while more follow
+pub fn scan_page<K, T>(
+    entries: impl Iterator<Item = (K, Option<T>)>,
+    limit: Option<u64>,
+) -> CursorPage<T, K> {
+    let limit = page_limit(limit).max(1) as usize;
+    let mut entries = entries.peekable();
+    let mut items = Vec::new();
+    let mut last = None;
+
+    for _ in 0..MAX_SCAN {
+        if items.len() == limit {
+            break;
+        }
+        match entries.next() {
+            Some((key, item)) => {
+                items.extend(item);
+                last = Some(key);
+            }
+            None => break,
+        }
+    }
+
+    CursorPage {
+        items,
+        next: last.filter(|_| entries.peek().is_some()),
+    }
+}
+
+// `page_range` as a slice range, for the id lists kept inside an entity
+pub fn page_slice(total: u64, from_index: Option<u64>, limit: Option<u64>) -> Range<usize> {
+    let range = page_range(total, from_index, limit);
+
+    range.start as usize..range.end as usize
+}
\ No newline at end of file
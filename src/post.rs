@@ -0,0 +1,58 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, AccountId, Timestamp};
+
+use crate::team::TeamId;
+
+// Define the Post structure - "looking for team" when team_id is None, otherwise "looking for members"
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct Post {
+    pub author: AccountId,
+    pub team_id: Option<TeamId>,
+    pub skills: Vec<String>,
+    pub message: String,
+    pub time: Timestamp,
+}
+
+// Implement the Post structure
+impl Post {
+    pub fn new(team_id: Option<TeamId>, skills: Vec<String>, message: String) -> Self {
+        // Skills are compared case-insensitively when matching
+        let mut skills: Vec<String> = skills
+            .iter()
+            .map(|skill| skill.trim().to_lowercase())
+            .filter(|skill| !skill.is_empty())
+            .collect();
+        skills.sort();
+        skills.dedup();
+
+        Post {
+            author: env::predecessor_account_id(),
+            team_id,
+            skills,
+            message,
+            time: env::block_timestamp(),
+        }
+    }
+
+    pub fn overlap(&self, other: &Post) -> Vec<String> {
+        self.skills
+            .iter()
+            .filter(|skill| other.skills.contains(skill))
+            .cloned()
+            .collect()
+    }
+}
+
+// Define the PostMatch structure (response) - a solo member and an open team sharing skills
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct PostMatch {
+    pub account_id: AccountId,
+    pub team_id: TeamId,
+    pub skills: Vec<String>,
+    pub score: u32,
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, Balance, StorageUsage};
+
+// Bytes a new account is expected to need for its storage record and member profile
+pub const MIN_STORAGE_BYTES: StorageUsage = 1_000;
+
+// Define the StorageAccount structure - what an account deposited and how many bytes it uses
+#[derive(BorshDeserialize, BorshSerialize, Debug, Default)]
+pub struct StorageAccount {
+    pub total: Balance,
+    pub used_bytes: StorageUsage,
+}
+
+// Implement the StorageAccount structure
+impl StorageAccount {
+    pub fn used(&self) -> Balance {
+        self.used_bytes as Balance * env::storage_byte_cost()
+    }
+
+    pub fn available(&self) -> Balance {
+        self.total.saturating_sub(self.used())
+    }
+
+    pub fn balance(&self) -> StorageBalance {
+        StorageBalance {
+            total: U128(self.total),
+            available: U128(self.available()),
+        }
+    }
+}
+
+// Define the NEP-145 storage balance structure (response)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct StorageBalance {
+    pub total: U128,
+    pub available: U128,
+}
+
+// Define the NEP-145 storage balance bounds structure (response)
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct StorageBalanceBounds {
+    pub min: U128,
+    pub max: Option<U128>,
+}
\ No newline at end of file
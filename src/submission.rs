@@ -0,0 +1,175 @@
+use near_sdk::{env, Timestamp};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::{ AccountId, serde::{Serialize, Deserialize}};
+
+pub type SubmissionId = u64;
+
+use crate::artifact::Artifact;
+use crate::category::{CategoryId, Category};
+use crate::content::{CommitAnchor, ContentRef};
+use crate::member::MemberJson;
+use crate::team::TeamId;
+use crate::version::Versioned;
+
+// Define the Submission structure
+#[derive(BorshDeserialize, BorshSerialize,Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Submission {
+    pub id: SubmissionId,
+    pub team_id: TeamId,
+    pub name: String,
+    pub description: String,
+    pub image: String,
+    pub time: Timestamp,
+    pub artifacts: Vec<Artifact>,
+    pub categories: Vec<CategoryId>,
+    pub members: Vec<AccountId>,
+    pub content: Option<ContentRef>,
+    pub commit: Option<CommitAnchor>,
+    pub revisions: u32,
+    // Pays for the storage of the submission, `None` for submissions from before storage was paid per entity
+    pub payer: Option<AccountId>
+}
+
+// Implement the Submission structure
+impl Submission {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(submission_id: u64, team_id: TeamId, categories: Vec<u64>, members: Vec<AccountId>, name: String, description: String, image: String, artifacts: Vec<Artifact>, time: Timestamp ) -> Self {
+        Submission { id: submission_id, team_id, categories, members, name, description, image, artifacts, time, content: None, commit: None, revisions: 0, payer: Some(env::predecessor_account_id()) }
+    }
+
+    // The artifact values as plain strings, which is what the old `link` field held
+    pub fn link(&self) -> Vec<String> {
+        self.artifacts.iter().map(|artifact| artifact.value.clone()).collect()
+    }
+
+    // Snapshot the editable content as the next revision
+    pub fn next_revision(&mut self) -> SubmissionRevision {
+        let revision = SubmissionRevision {
+            number: self.revisions,
+            editor: env::predecessor_account_id(),
+            time: env::block_timestamp(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            image: self.image.clone(),
+            artifacts: self.artifacts.clone(),
+            categories: self.categories.clone(),
+            content: self.content.clone(),
+            commit: self.commit.clone(),
+        };
+        self.revisions += 1;
+        revision
+    }
+} 
+
+// Define the Detail Submission Json structure
+#[derive(BorshDeserialize, BorshSerialize,Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct SubmissionJson {
+    pub id: SubmissionId,
+    pub team_id: TeamId,
+    pub name: String,
+    pub description: String,
+    pub image: String,
+    pub time: Timestamp,
+    // Deprecated, the values of `artifacts` for clients that predate typed artifacts
+    pub link: Vec<String>,
+    pub artifacts: Vec<Artifact>,
+    pub categories: Vec<Category>,
+    pub members: Vec<MemberJson>,
+    pub content: Option<ContentRef>,
+    pub commit: Option<CommitAnchor>,
+    pub revisions: u32
+}
+
+// Define the Submission Revision structure - an immutable snapshot written on submit and on every update
+#[derive(BorshDeserialize, BorshSerialize,Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct SubmissionRevision {
+    pub number: u32,
+    pub editor: AccountId,
+    pub time: Timestamp,
+    pub name: String,
+    pub description: String,
+    pub image: String,
+    pub artifacts: Vec<Artifact>,
+    pub categories: Vec<CategoryId>,
+    pub content: Option<ContentRef>,
+    pub commit: Option<CommitAnchor>
+}
+
+// Define the Submission update payload structure (request) - only the given fields are changed
+#[derive(BorshDeserialize, BorshSerialize,Serialize, Deserialize, Debug, Default)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct SubmissionUpdatePayload {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    // Deprecated, converted with `Artifact::from_link` and put in front of `artifacts`
+    pub link: Option<Vec<String>>,
+    pub artifacts: Option<Vec<Artifact>>,
+    pub categories: Option<Vec<CategoryId>>,
+    pub content: Option<ContentRef>
+}
+
+// Define the submission layout before state versioning, when submissions had no team
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct SubmissionV0 {
+    pub id: SubmissionId,
+    pub name: String,
+    pub description: String,
+    pub image: String,
+    pub time: Timestamp,
+    pub link: Vec<String>,
+    pub categories: Vec<CategoryId>,
+    pub members: Vec<AccountId>
+}
+
+// Implement the SubmissionV0 structure
+impl SubmissionV0 {
+    pub fn migrate(self, team_id: TeamId) -> Submission {
+        Submission {
+            id: self.id,
+            team_id,
+            name: self.name,
+            description: self.description,
+            image: self.image,
+            time: self.time,
+            artifacts: self.link.into_iter().map(Artifact::from_link).collect(),
+            categories: self.categories,
+            members: self.members,
+            content: None,
+            commit: None,
+            revisions: 0,
+            payer: None,
+        }
+    }
+}
+
+// Define the versioned submission, as it is stored
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum VersionedSubmission {
+    V0(SubmissionV0),
+    V1(Submission),
+}
+
+// Implement the versioned submission - older submissions get their team during `migrate`
+impl Versioned for VersionedSubmission {
+    type Entity = Submission;
+    type Legacy = SubmissionV0;
+    const CURRENT: u8 = 1;
+
+    fn from_legacy(legacy: SubmissionV0) -> Self {
+        VersionedSubmission::V0(legacy)
+    }
+
+    fn into_current(self) -> Submission {
+        match self {
+            VersionedSubmission::V0(_) => env::panic_str("Submission not migrated"),
+            VersionedSubmission::V1(submission) => submission,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,124 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, AccountId, Timestamp};
+
+use crate::hackathon::HackathonId;
+use crate::submission::SubmissionId;
+use crate::version::Versioned;
+
+pub type TeamId = u64;
+
+// Define the kind of membership change recorded on a team
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub enum TeamAction {
+    Created,
+    Invited,
+    Joined,
+    Declined,
+    Left,
+}
+
+// Define the TeamChange structure - one entry of a team's membership history
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct TeamChange {
+    pub account_id: AccountId,
+    pub action: TeamAction,
+    pub time: Timestamp,
+}
+
+// Define the Team structure
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct Team {
+    pub id: TeamId,
+    pub hackathon_id: HackathonId,
+    pub name: String,
+    pub leader: AccountId,
+    pub members: Vec<AccountId>,
+    pub invitations: Vec<AccountId>,
+    pub submissions: Vec<SubmissionId>,
+    pub history: Vec<TeamChange>,
+    // Pays for the storage of the team, `None` for teams from before storage was paid per entity
+    pub payer: Option<AccountId>,
+}
+
+// Implement the Team structure
+impl Team {
+    pub fn new(id: TeamId, hackathon_id: HackathonId, name: String) -> Self {
+        let leader = env::predecessor_account_id();
+        let mut team = Team {
+            id,
+            hackathon_id,
+            name,
+            leader: leader.clone(),
+            members: vec![leader.clone()],
+            invitations: Vec::new(),
+            submissions: Vec::new(),
+            history: Vec::new(),
+            payer: Some(leader.clone()),
+        };
+        team.record(leader, TeamAction::Created);
+        team
+    }
+
+    // A team for a submission made before teams existed, led by its first member
+    pub fn for_submission(
+        id: TeamId,
+        hackathon_id: HackathonId,
+        name: String,
+        members: Vec<AccountId>,
+        submission_id: SubmissionId,
+    ) -> Self {
+        let mut team = Team {
+            id,
+            hackathon_id,
+            name,
+            leader: members.first().cloned().expect("Submission has no members"),
+            members: members.clone(),
+            invitations: Vec::new(),
+            submissions: vec![submission_id],
+            history: Vec::new(),
+            payer: None,
+        };
+        for account_id in members {
+            team.record(account_id, TeamAction::Joined);
+        }
+        team
+    }
+
+    pub fn record(&mut self, account_id: AccountId, action: TeamAction) {
+        self.history.push(TeamChange {
+            account_id,
+            action,
+            time: env::block_timestamp(),
+        });
+    }
+}
+
+// Define the versioned team, as it is stored - teams were added after the first version
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum VersionedTeam {
+    V0(Team),
+}
+
+// Implement the versioned team
+impl Versioned for VersionedTeam {
+    type Entity = Team;
+    type Legacy = Team;
+    const CURRENT: u8 = 0;
+
+    fn from_legacy(legacy: Team) -> Self {
+        VersionedTeam::V0(legacy)
+    }
+
+    fn into_current(self) -> Team {
+        match self {
+            VersionedTeam::V0(team) => team,
+        }
+    }
+}
\ No newline at end of file
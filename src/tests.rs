@@ -0,0 +1,3303 @@
+use mock_dao::{Action, MockDao};
+use near_sdk::mock::VmAction;
+use near_sdk::serde_json::json;
+use near_sdk::test_utils::{accounts, get_created_receipts, get_logs, VMContextBuilder};
+use near_sdk::{
+    serde_json, testing_env, AccountId, PromiseResult, RuntimeFeesConfig, VMConfig, ONE_NEAR,
+};
+
+use super::*;
+use artifact::ArtifactKind;
+
+const START: Timestamp = 1_000;
+const BUILDING: Timestamp = 5_000;
+const END: Timestamp = 10_000;
+const AFTER_END: Timestamp = 20_000;
+
+fn set_context(signer: AccountId, time: Timestamp, deposit: Balance) {
+    testing_env!(VMContextBuilder::new()
+        .signer_account_id(signer.clone())
+        .predecessor_account_id(signer)
+        .block_timestamp(time)
+        .attached_deposit(deposit)
+        .build());
+}
+
+fn hackathon_payload(name: &str) -> HackathonPayload {
+    serde_json::from_value(json!({
+        "name": name,
+        "description": "description",
+        "tags": ["near"],
+        "image": "image",
+        "start": START,
+        "end": END,
+    }))
+    .unwrap()
+}
+
+struct Setup {
+    contract: HackathonContract,
+    hackathon_id: HackathonId,
+    category_id: CategoryId,
+    award_id: AwardId,
+    team_id: TeamId,
+}
+
+// alice owns a hackathon with one category and award; bob and charlie form a team in it
+fn setup() -> Setup {
+    set_context(accounts(0), 0, 0);
+    let mut contract = HackathonContract::init(None, None);
+
+    for i in 0..4 {
+        set_context(accounts(i), 0, ONE_NEAR);
+        contract.storage_deposit(None, None);
+        set_context(accounts(i), 0, 0);
+        contract.add_member(format!("member {}", i), None, None);
+    }
+
+    set_context(accounts(0), 0, 0);
+    let hackathon_id = contract.add_hackathon(hackathon_payload("h")).unwrap();
+    let category_id = contract
+        .add_category(hackathon_id, "c".to_string())
+        .unwrap();
+    let award_id = contract
+        .add_award(hackathon_id, category_id, "a".to_string(), 1.0)
+        .unwrap();
+
+    set_context(accounts(1), 0, 0);
+    contract.join_hackathon(hackathon_id);
+    set_context(accounts(2), 0, 0);
+    contract.join_hackathon(hackathon_id);
+
+    set_context(accounts(1), BUILDING, 0);
+    let team_id = contract.create_team(hackathon_id, "t".to_string());
+    contract.invite_to_team(team_id, accounts(2));
+    set_context(accounts(2), BUILDING, 0);
+    contract.accept_team_invitation(team_id);
+
+    Setup {
+        contract,
+        hackathon_id,
+        category_id,
+        award_id,
+        team_id,
+    }
+}
+
+impl Setup {
+    fn submit(&mut self, signer: AccountId, categories: Vec<CategoryId>) -> SubmissionId {
+        set_context(signer, BUILDING, 0);
+        let submission_id = self.contract.next_submission_id;
+        self.contract.submit_project(
+            self.hackathon_id,
+            self.team_id,
+            categories,
+            "project".to_string(),
+            "description".to_string(),
+            "image".to_string(),
+            vec!["https://example.com".to_string()],
+            BUILDING,
+            None,
+        );
+        submission_id
+    }
+
+    // A second hackathon by alice with its own category and award, for cross-hackathon checks
+    fn other_hackathon(&mut self) -> (HackathonId, CategoryId, AwardId) {
+        set_context(accounts(0), 0, 0);
+        let hackathon_id = self
+            .contract
+            .add_hackathon(hackathon_payload("other"))
+            .unwrap();
+        let category_id = self
+            .contract
+            .add_category(hackathon_id, "other".to_string())
+            .unwrap();
+        let award_id = self
+            .contract
+            .add_award(hackathon_id, category_id, "other".to_string(), 1.0)
+            .unwrap();
+        (hackathon_id, category_id, award_id)
+    }
+}
+
+#[test]
+fn submit_judge_and_award() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    let submission = s.contract.submissions.get(&submission_id).unwrap();
+    assert_eq!(submission.members, vec![accounts(1), accounts(2)]);
+
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+    set_context(accounts(0), AFTER_END, ONE_NEAR);
+    s.contract
+        .award_winner(s.hackathon_id, s.category_id, s.award_id);
+
+    let award = s.contract.awards.get(&s.award_id).unwrap();
+    assert_eq!(award.winner, Some(submission_id));
+    assert!(award.is_awarded);
+}
+
+#[test]
+#[should_panic(expected = "Member exists")]
+fn add_member_twice() {
+    let mut s = setup();
+    set_context(accounts(1), 0, 0);
+    s.contract.add_member("again".to_string(), None, None);
+}
+
+#[test]
+#[should_panic(expected = "Not a member")]
+fn add_hackathon_not_member() {
+    let mut s = setup();
+    set_context(accounts(5), 0, 0);
+    s.contract.add_hackathon(hackathon_payload("h"));
+}
+
+#[test]
+#[should_panic(expected = "Start must be before end")]
+fn add_hackathon_end_before_start() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    let payload = serde_json::from_value(json!({
+        "name": "h",
+        "description": "description",
+        "tags": [],
+        "image": "image",
+        "start": END,
+        "end": START,
+    }))
+    .unwrap();
+    s.contract.add_hackathon(payload);
+}
+
+#[test]
+#[should_panic(expected = "Not organizer")]
+fn add_category_not_organizer() {
+    let mut s = setup();
+    set_context(accounts(1), 0, 0);
+    s.contract.add_category(s.hackathon_id, "c".to_string());
+}
+
+#[test]
+#[should_panic(expected = "category is not in hackathon")]
+fn add_award_foreign_category() {
+    let mut s = setup();
+    let (_, other_category, _) = s.other_hackathon();
+    set_context(accounts(0), 0, 0);
+    s.contract
+        .add_award(s.hackathon_id, other_category, "a".to_string(), 1.0);
+}
+
+#[test]
+#[should_panic(expected = "Not a member")]
+fn join_hackathon_not_member() {
+    let mut s = setup();
+    set_context(accounts(5), 0, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+}
+
+#[test]
+#[should_panic(expected = "Hackathon not exist")]
+fn join_hackathon_missing() {
+    let mut s = setup();
+    set_context(accounts(3), 0, 0);
+    s.contract.join_hackathon(42);
+}
+
+#[test]
+#[should_panic(expected = "Can't join your own")]
+fn join_own_hackathon() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+}
+
+#[test]
+#[should_panic(expected = "Existing in hackathon")]
+fn join_hackathon_twice() {
+    let mut s = setup();
+    set_context(accounts(1), 0, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+}
+
+#[test]
+#[should_panic(expected = "Hackathon ended")]
+fn join_ended_hackathon() {
+    let mut s = setup();
+    set_context(accounts(3), AFTER_END, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+}
+
+#[test]
+#[should_panic(expected = "Hackathon not exist")]
+fn submit_missing_hackathon() {
+    let mut s = setup();
+    s.hackathon_id = 42;
+    s.submit(accounts(1), vec![]);
+}
+
+#[test]
+#[should_panic(expected = "Submission deadline passed")]
+fn submit_after_deadline() {
+    let mut s = setup();
+    set_context(accounts(1), AFTER_END, 0);
+    s.contract.submit_project(
+        s.hackathon_id,
+        s.team_id,
+        vec![s.category_id],
+        "project".to_string(),
+        "description".to_string(),
+        "image".to_string(),
+        vec![],
+        AFTER_END,
+        None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Team is not in hackathon")]
+fn submit_foreign_team() {
+    let mut s = setup();
+    let (other_hackathon_id, _, _) = s.other_hackathon();
+    s.hackathon_id = other_hackathon_id;
+    s.submit(accounts(1), vec![]);
+}
+
+#[test]
+#[should_panic(expected = "Not a member of the team")]
+fn submit_not_in_team() {
+    let mut s = setup();
+    s.submit(accounts(3), vec![s.category_id]);
+}
+
+#[test]
+#[should_panic(expected = "category is not in hackathon")]
+fn submit_foreign_category() {
+    let mut s = setup();
+    let (_, other_category, _) = s.other_hackathon();
+    s.submit(accounts(1), vec![other_category]);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate category")]
+fn submit_duplicate_category() {
+    let mut s = setup();
+    s.submit(accounts(1), vec![s.category_id, s.category_id]);
+}
+
+#[test]
+#[should_panic(expected = "category is not in hackathon")]
+fn update_submission_foreign_category() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+    let (_, other_category, _) = s.other_hackathon();
+
+    set_context(accounts(2), BUILDING, 0);
+    s.contract.update_submission(
+        submission_id,
+        SubmissionUpdatePayload {
+            categories: Some(vec![other_category]),
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Not a member of the submission")]
+fn update_submission_not_member() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(3), BUILDING, 0);
+    s.contract
+        .update_submission(submission_id, SubmissionUpdatePayload::default());
+}
+
+#[test]
+#[should_panic(expected = "Not organizer")]
+fn judge_not_organizer() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(1), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+}
+
+#[test]
+#[should_panic(expected = "Submission is not in hackathon")]
+fn judge_foreign_submission() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+    let (other_hackathon_id, other_category, other_award) = s.other_hackathon();
+
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract.judge_winner(
+        other_hackathon_id,
+        other_category,
+        other_award,
+        submission_id,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Submission is not in category")]
+fn judge_submission_outside_category() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![]);
+
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+}
+
+#[test]
+#[should_panic(expected = "category is not in hackathon")]
+fn judge_foreign_category() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+    let (_, other_category, other_award) = s.other_hackathon();
+
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, other_category, other_award, submission_id);
+}
+
+#[test]
+#[should_panic(expected = "award is not in category")]
+fn judge_foreign_award() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+    let (_, _, other_award) = s.other_hackathon();
+
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, other_award, submission_id);
+}
+
+#[test]
+#[should_panic(expected = "Winner judged")]
+fn judge_twice() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+}
+
+#[test]
+#[should_panic(expected = "Winner not judged")]
+fn award_without_winner() {
+    let mut s = setup();
+    set_context(accounts(0), AFTER_END, ONE_NEAR);
+    s.contract
+        .award_winner(s.hackathon_id, s.category_id, s.award_id);
+}
+
+#[test]
+#[should_panic(expected = "Not owner")]
+fn award_not_owner() {
+    let mut s = setup();
+    set_context(accounts(1), AFTER_END, ONE_NEAR);
+    s.contract
+        .award_winner(s.hackathon_id, s.category_id, s.award_id);
+}
+
+#[test]
+#[should_panic(expected = "attached deposit should be equal to the unfunded part of the award")]
+fn award_wrong_deposit() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+    set_context(accounts(0), AFTER_END, ONE_NEAR / 2);
+    s.contract
+        .award_winner(s.hackathon_id, s.category_id, s.award_id);
+}
+
+#[test]
+fn leave_hackathon_drops_participant() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    s.contract.leave_hackathon(s.hackathon_id);
+
+    let hackathon = s.contract.hackathons.get(&s.hackathon_id).unwrap();
+    assert!(!hackathon.participants_list.contains(&accounts(3)));
+    let member = s.contract.members.get(&accounts(3)).unwrap();
+    assert!(member.joined_hackathons.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Not participated in hackathon")]
+fn leave_hackathon_not_joined() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.leave_hackathon(s.hackathon_id);
+}
+
+#[test]
+#[should_panic(expected = "Member of a team, leave it first")]
+fn leave_hackathon_in_team() {
+    let mut s = setup();
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.leave_hackathon(s.hackathon_id);
+}
+
+#[test]
+#[should_panic(expected = "Submission deadline passed")]
+fn leave_hackathon_after_end() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    set_context(accounts(3), AFTER_END, 0);
+    s.contract.leave_hackathon(s.hackathon_id);
+}
+
+#[test]
+fn withdraw_submission_clears_unpaid_award() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+    set_context(accounts(2), AFTER_END, 0);
+    s.contract
+        .withdraw_submission(s.hackathon_id, submission_id);
+
+    assert!(s.contract.submissions.get(&submission_id).is_none());
+    assert_eq!(s.contract.awards.get(&s.award_id).unwrap().winner, None);
+    let hackathon = s.contract.hackathons.get(&s.hackathon_id).unwrap();
+    assert!(!hackathon.submissions_list.contains(&submission_id));
+    assert!(s
+        .contract
+        .teams
+        .get(&s.team_id)
+        .unwrap()
+        .submissions
+        .is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Not a member of the submission")]
+fn withdraw_submission_not_member() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(3), BUILDING, 0);
+    s.contract
+        .withdraw_submission(s.hackathon_id, submission_id);
+}
+
+#[test]
+#[should_panic(expected = "Award already paid to submission")]
+fn withdraw_paid_submission() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+    set_context(accounts(0), AFTER_END, ONE_NEAR);
+    s.contract
+        .award_winner(s.hackathon_id, s.category_id, s.award_id);
+    set_context(accounts(1), AFTER_END, 0);
+    s.contract
+        .withdraw_submission(s.hackathon_id, submission_id);
+}
+
+#[test]
+fn updates_are_applied_and_recorded_as_edits() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    let payload = serde_json::from_value(json!({ "name": "renamed", "end": END + 1 })).unwrap();
+    s.contract.update_hackathon(s.hackathon_id, payload);
+    s.contract
+        .update_category(s.hackathon_id, s.category_id, "c2".to_string());
+    s.contract.update_award(
+        s.hackathon_id,
+        s.category_id,
+        s.award_id,
+        Some("a2".to_string()),
+        Some(2.0),
+    );
+
+    let hackathon = s.contract.hackathons.get(&s.hackathon_id).unwrap();
+    assert_eq!(hackathon.name, "renamed");
+    assert_eq!(hackathon.end, END + 1);
+    assert_eq!(
+        s.contract.categories.get(&s.category_id).unwrap().name,
+        "c2"
+    );
+    let award = s.contract.awards.get(&s.award_id).unwrap();
+    assert_eq!(award.name, "a2");
+    assert_eq!(award.price.0, 2 * ONE_NEAR);
+
+    let edits = s.contract.get_hackathon_edits(s.hackathon_id, None, None);
+    let fields: Vec<(EditTarget, &str)> = edits
+        .items
+        .iter()
+        .map(|edit| (edit.target.clone(), edit.field.as_str()))
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            (EditTarget::Hackathon, "name"),
+            (EditTarget::Hackathon, "end"),
+            (EditTarget::Category(s.category_id), "name"),
+            (EditTarget::Award(s.award_id), "name"),
+            (EditTarget::Award(s.award_id), "price"),
+        ]
+    );
+    assert_eq!(edits.items[0].old_value, "h");
+    assert!(edits.items.iter().all(|edit| edit.editor == accounts(0)));
+}
+
+#[test]
+#[should_panic(expected = "Not owner")]
+fn update_hackathon_not_owner() {
+    let mut s = setup();
+    set_context(accounts(1), 0, 0);
+    s.contract
+        .update_hackathon(s.hackathon_id, HackathonUpdatePayload::default());
+}
+
+#[test]
+#[should_panic(expected = "Hackathon already started")]
+fn update_start_after_start() {
+    let mut s = setup();
+    set_context(accounts(0), BUILDING, 0);
+    let payload = serde_json::from_value(json!({ "start": START + 1 })).unwrap();
+    s.contract.update_hackathon(s.hackathon_id, payload);
+}
+
+#[test]
+#[should_panic(expected = "Can't shorten a passed deadline")]
+fn update_end_shortens_passed_deadline() {
+    let mut s = setup();
+    set_context(accounts(0), AFTER_END, 0);
+    let payload = serde_json::from_value(json!({ "end": END - 1 })).unwrap();
+    s.contract.update_hackathon(s.hackathon_id, payload);
+}
+
+#[test]
+#[should_panic(expected = "Not organizer")]
+fn update_category_not_organizer() {
+    let mut s = setup();
+    set_context(accounts(1), 0, 0);
+    s.contract
+        .update_category(s.hackathon_id, s.category_id, "c2".to_string());
+}
+
+#[test]
+#[should_panic(expected = "Can't lower a prize after the hackathon started")]
+fn update_award_lowers_prize_after_start() {
+    let mut s = setup();
+    set_context(accounts(0), BUILDING, 0);
+    s.contract
+        .update_award(s.hackathon_id, s.category_id, s.award_id, None, Some(0.5));
+}
+
+#[test]
+#[should_panic(expected = "award awarded")]
+fn update_paid_award() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+    set_context(accounts(0), AFTER_END, ONE_NEAR);
+    s.contract
+        .award_winner(s.hackathon_id, s.category_id, s.award_id);
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .update_award(s.hackathon_id, s.category_id, s.award_id, None, Some(2.0));
+}
+
+#[test]
+fn co_organizer_manages_categories_and_judges() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(0), BUILDING, 0);
+    s.contract.add_organizer(s.hackathon_id, accounts(3));
+    set_context(accounts(3), BUILDING, 0);
+    assert!(s
+        .contract
+        .add_category(s.hackathon_id, "c2".to_string())
+        .is_some());
+    set_context(accounts(3), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+
+    assert_eq!(
+        s.contract.awards.get(&s.award_id).unwrap().winner,
+        Some(submission_id)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Not owner")]
+fn co_organizer_cannot_add_organizers() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.add_organizer(s.hackathon_id, accounts(3));
+    set_context(accounts(3), 0, 0);
+    s.contract.add_organizer(s.hackathon_id, accounts(1));
+}
+
+#[test]
+#[should_panic(expected = "Existing in hackathon")]
+fn participant_cannot_be_organizer() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.add_organizer(s.hackathon_id, accounts(1));
+}
+
+#[test]
+#[should_panic(expected = "Not organizer")]
+fn removed_organizer_loses_access() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.add_organizer(s.hackathon_id, accounts(3));
+    s.contract.remove_organizer(s.hackathon_id, accounts(3));
+    set_context(accounts(3), 0, 0);
+    s.contract.add_category(s.hackathon_id, "c2".to_string());
+}
+
+#[test]
+fn hackathon_owner_is_transferred_in_two_steps() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.add_organizer(s.hackathon_id, accounts(3));
+    s.contract.propose_owner(s.hackathon_id, Some(accounts(3)));
+    assert_eq!(
+        s.contract.hackathons.get(&s.hackathon_id).unwrap().owner,
+        accounts(0)
+    );
+
+    set_context(accounts(3), 0, 0);
+    s.contract.accept_owner(s.hackathon_id);
+
+    let hackathon = s.contract.hackathons.get(&s.hackathon_id).unwrap();
+    assert_eq!(hackathon.owner, accounts(3));
+    assert_eq!(hackathon.pending_owner, None);
+    assert!(hackathon.organizers.is_empty());
+    assert!(s
+        .contract
+        .members
+        .get(&accounts(0))
+        .unwrap()
+        .created_hackathons
+        .is_empty());
+    assert_eq!(
+        s.contract
+            .members
+            .get(&accounts(3))
+            .unwrap()
+            .created_hackathons,
+        vec![s.hackathon_id]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Not proposed owner")]
+fn cancelled_owner_proposal_cannot_be_accepted() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.propose_owner(s.hackathon_id, Some(accounts(3)));
+    s.contract.propose_owner(s.hackathon_id, None);
+    set_context(accounts(3), 0, 0);
+    s.contract.accept_owner(s.hackathon_id);
+}
+
+#[test]
+#[should_panic(expected = "Existing in hackathon")]
+fn participant_cannot_accept_ownership() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.propose_owner(s.hackathon_id, Some(accounts(1)));
+    set_context(accounts(1), 0, 0);
+    s.contract.accept_owner(s.hackathon_id);
+}
+
+#[test]
+fn declined_invitation_is_dropped() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.invite_to_team(s.team_id, accounts(3));
+    assert_eq!(
+        s.contract.members.get(&accounts(3)).unwrap().invitations,
+        vec![s.team_id]
+    );
+
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.decline_team_invitation(s.team_id);
+
+    let team = s.contract.teams.get(&s.team_id).unwrap();
+    assert!(team.invitations.is_empty());
+    assert_eq!(team.members, vec![accounts(1), accounts(2)]);
+    assert_eq!(team.history.last().unwrap().action, TeamAction::Declined);
+    assert!(s
+        .contract
+        .members
+        .get(&accounts(3))
+        .unwrap()
+        .invitations
+        .is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Not invited")]
+fn accept_without_invitation() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    s.contract.accept_team_invitation(s.team_id);
+}
+
+#[test]
+#[should_panic(expected = "Already invited")]
+fn invite_twice() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.invite_to_team(s.team_id, accounts(3));
+    s.contract.invite_to_team(s.team_id, accounts(3));
+}
+
+#[test]
+#[should_panic(expected = "Not participated in hackathon")]
+fn invite_non_participant() {
+    let mut s = setup();
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.invite_to_team(s.team_id, accounts(3));
+}
+
+#[test]
+#[should_panic(expected = "Already in a team")]
+fn invite_member_of_a_team() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    s.contract.create_team(s.hackathon_id, "t2".to_string());
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.invite_to_team(s.team_id, accounts(3));
+}
+
+#[test]
+#[should_panic(expected = "Not a member of the team")]
+fn invite_by_outsider() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    s.contract.invite_to_team(s.team_id, accounts(3));
+}
+
+#[test]
+fn leaving_leader_hands_over_the_team() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.leave_team(s.team_id);
+
+    let team = s.contract.teams.get(&s.team_id).unwrap();
+    assert_eq!(team.leader, accounts(2));
+    assert_eq!(team.members, vec![accounts(2)]);
+    assert_eq!(
+        s.contract.submissions.get(&submission_id).unwrap().members,
+        vec![accounts(2)]
+    );
+}
+
+#[test]
+fn winning_submission_keeps_its_members() {
+    let mut s = setup();
+    let winner_id = s.submit(accounts(1), vec![s.category_id]);
+    let other_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(0), BUILDING, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, winner_id);
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.leave_team(s.team_id);
+
+    assert_eq!(
+        s.contract.submissions.get(&winner_id).unwrap().members,
+        vec![accounts(1), accounts(2)]
+    );
+    assert_eq!(
+        s.contract.submissions.get(&other_id).unwrap().members,
+        vec![accounts(2)]
+    );
+}
+
+fn set_settings(s: &mut Setup, settings: serde_json::Value) {
+    set_context(accounts(0), BUILDING, 0);
+    let payload = serde_json::from_value(json!({ "settings": settings })).unwrap();
+    s.contract.update_hackathon(s.hackathon_id, payload);
+}
+
+#[test]
+fn submission_within_limits() {
+    let mut s = setup();
+    set_settings(
+        &mut s,
+        json!({
+            "min_team_size": 2,
+            "max_team_size": 2,
+            "max_submissions_per_team": 1,
+            "max_categories_per_submission": 1,
+        }),
+    );
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    assert!(s.contract.submissions.get(&submission_id).is_some());
+}
+
+#[test]
+#[should_panic(expected = "Rule min_team_size 3 is above max_team_size 2")]
+fn invalid_settings() {
+    let mut s = setup();
+    set_settings(&mut s, json!({ "min_team_size": 3, "max_team_size": 2 }));
+}
+
+#[test]
+#[should_panic(expected = "Rule max_team_size violated: team already has 2 of 2 members")]
+fn invite_to_full_team() {
+    let mut s = setup();
+    set_settings(&mut s, json!({ "max_team_size": 2 }));
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.invite_to_team(s.team_id, accounts(3));
+}
+
+#[test]
+#[should_panic(expected = "Rule min_team_size violated: team has 2 members, at least 3 required")]
+fn submit_with_small_team() {
+    let mut s = setup();
+    set_settings(&mut s, json!({ "min_team_size": 3 }));
+    s.submit(accounts(1), vec![s.category_id]);
+}
+
+#[test]
+#[should_panic(
+    expected = "Rule max_submissions_per_team violated: team already made 1 of 1 submissions"
+)]
+fn submit_over_submission_limit() {
+    let mut s = setup();
+    set_settings(&mut s, json!({ "max_submissions_per_team": 1 }));
+    s.submit(accounts(1), vec![s.category_id]);
+    s.submit(accounts(2), vec![s.category_id]);
+}
+
+#[test]
+#[should_panic(
+    expected = "Rule max_categories_per_submission violated: 2 categories given, at most 1 allowed"
+)]
+fn submit_over_category_limit() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    let category_id = s
+        .contract
+        .add_category(s.hackathon_id, "c2".to_string())
+        .unwrap();
+    set_settings(&mut s, json!({ "max_categories_per_submission": 1 }));
+    s.submit(accounts(1), vec![s.category_id, category_id]);
+}
+
+fn skills(skills: &[&str]) -> Vec<String> {
+    skills.iter().map(|skill| skill.to_string()).collect()
+}
+
+#[test]
+fn board_matches_solo_members_with_teams() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    s.contract.post_looking_for_team(
+        s.hackathon_id,
+        skills(&["Rust", " react ", "rust"]),
+        "hi".to_string(),
+    );
+    set_context(accounts(1), BUILDING, 0);
+    s.contract
+        .post_looking_for_members(s.team_id, skills(&["rust", "design"]), "join".to_string());
+
+    let posts = s.contract.get_posts(s.hackathon_id, None, None);
+    assert_eq!(posts.total, 2);
+    assert_eq!(posts.items[0].skills, skills(&["react", "rust"]));
+
+    let matches = s.contract.get_post_matches(s.hackathon_id, None, None);
+    assert_eq!(matches.total, 1);
+    assert_eq!(matches.items[0].account_id, accounts(3));
+    assert_eq!(matches.items[0].team_id, s.team_id);
+    assert_eq!(matches.items[0].skills, skills(&["rust"]));
+    assert_eq!(matches.items[0].score, 1);
+}
+
+#[test]
+fn posts_are_replaced_and_removed() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    s.contract
+        .post_looking_for_team(s.hackathon_id, skills(&["rust"]), "one".to_string());
+    s.contract
+        .post_looking_for_team(s.hackathon_id, skills(&["rust"]), "two".to_string());
+    set_context(accounts(2), BUILDING, 0);
+    s.contract
+        .post_looking_for_members(s.team_id, skills(&["rust"]), "join".to_string());
+
+    let posts = s.contract.get_posts(s.hackathon_id, None, None);
+    assert_eq!(posts.total, 2);
+    assert_eq!(posts.items[0].message, "two");
+
+    // Joining the team takes the solo post off the board
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.invite_to_team(s.team_id, accounts(3));
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.accept_team_invitation(s.team_id);
+    let posts = s.contract.get_posts(s.hackathon_id, None, None);
+    assert_eq!(posts.total, 1);
+    assert_eq!(posts.items[0].team_id, Some(s.team_id));
+
+    s.contract.remove_post(s.hackathon_id, Some(s.team_id));
+    assert_eq!(s.contract.get_posts(s.hackathon_id, None, None).total, 0);
+}
+
+#[test]
+fn ended_board_is_pruned() {
+    let mut s = setup();
+    set_context(accounts(1), BUILDING, 0);
+    s.contract
+        .post_looking_for_members(s.team_id, skills(&["rust"]), "join".to_string());
+
+    set_context(accounts(3), AFTER_END, 0);
+    assert_eq!(s.contract.get_posts(s.hackathon_id, None, None).total, 0);
+    s.contract.prune_posts(s.hackathon_id);
+    assert!(s.contract.posts.get(&s.hackathon_id).is_none());
+}
+
+#[test]
+#[should_panic(expected = "Hackathon not ended")]
+fn prune_building_board() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.prune_posts(s.hackathon_id);
+}
+
+#[test]
+#[should_panic(expected = "Already in a team")]
+fn team_member_looking_for_team() {
+    let mut s = setup();
+    set_context(accounts(1), BUILDING, 0);
+    s.contract
+        .post_looking_for_team(s.hackathon_id, skills(&["rust"]), "hi".to_string());
+}
+
+#[test]
+#[should_panic(expected = "Not a member of the team")]
+fn outsider_looking_for_members() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    s.contract
+        .post_looking_for_members(s.team_id, skills(&["rust"]), "join".to_string());
+}
+
+#[test]
+fn every_change_writes_a_revision() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(2), BUILDING + 1, 0);
+    let payload = serde_json::from_value(json!({ "name": "renamed" })).unwrap();
+    s.contract.update_submission(submission_id, payload);
+
+    let revisions = s
+        .contract
+        .get_submission_revisions(submission_id, None, None);
+    assert_eq!(revisions.total, 2);
+    assert_eq!(revisions.items[0].name, "project");
+    assert_eq!(revisions.items[0].editor, accounts(1));
+    assert_eq!(revisions.items[1].name, "renamed");
+    assert_eq!(revisions.items[1].editor, accounts(2));
+    assert_eq!(revisions.items[1].time, BUILDING + 1);
+    assert_eq!(
+        s.contract
+            .get_submission_revision(submission_id, 1)
+            .unwrap()
+            .name,
+        "renamed"
+    );
+    assert_eq!(
+        s.contract
+            .get_submission_revisions(submission_id, Some(1), None)
+            .items
+            .len(),
+        1
+    );
+}
+
+#[test]
+#[should_panic(expected = "Submission deadline passed")]
+fn update_submission_after_deadline() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(1), AFTER_END, 0);
+    s.contract
+        .update_submission(submission_id, SubmissionUpdatePayload::default());
+}
+
+#[test]
+fn withdrawn_submission_drops_its_revisions() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+    set_context(accounts(1), BUILDING, 0);
+    s.contract
+        .update_submission(submission_id, SubmissionUpdatePayload::default());
+
+    s.contract
+        .withdraw_submission(s.hackathon_id, submission_id);
+
+    for number in 0..2 {
+        assert!(!s
+            .contract
+            .submission_revisions
+            .contains_key(&(submission_id, number)));
+    }
+    assert_eq!(
+        s.contract
+            .get_submission_revisions(submission_id, None, None)
+            .total,
+        0
+    );
+}
+
+fn artifact(kind: ArtifactKind, value: &str) -> Artifact {
+    Artifact {
+        kind,
+        label: "label".to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn typed_artifacts_and_legacy_links() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.update_submission(
+        submission_id,
+        SubmissionUpdatePayload {
+            link: Some(vec!["https://github.com/near/project".to_string()]),
+            artifacts: Some(vec![
+                artifact(ArtifactKind::ContractAccount, "project.testnet"),
+                artifact(
+                    ArtifactKind::IpfsCid,
+                    "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+                ),
+            ]),
+            ..Default::default()
+        },
+    );
+
+    let submission = s.contract.get_submission_by_id(submission_id).unwrap();
+    let kinds: Vec<ArtifactKind> = submission.artifacts.iter().map(|a| a.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ArtifactKind::SourceRepo,
+            ArtifactKind::ContractAccount,
+            ArtifactKind::IpfsCid
+        ]
+    );
+    assert_eq!(submission.link[0], "https://github.com/near/project");
+}
+
+#[test]
+fn legacy_link_kind_comes_from_the_host() {
+    let kind = |link: &str| Artifact::from_link(link.to_string()).kind;
+
+    assert_eq!(
+        kind("https://www.GitHub.com/near/project"),
+        ArtifactKind::SourceRepo
+    );
+    assert_eq!(
+        kind("https://user@gitlab.com:443/near"),
+        ArtifactKind::SourceRepo
+    );
+    assert_eq!(kind("https://youtu.be/abc"), ArtifactKind::Video);
+    assert_eq!(kind("https://notgithub.com/near"), ArtifactKind::DemoUrl);
+    assert_eq!(
+        kind("https://example.com/github.com"),
+        ArtifactKind::DemoUrl
+    );
+    assert_eq!(kind("project.testnet"), ArtifactKind::ContractAccount);
+}
+
+#[test]
+#[should_panic(expected = "is not a valid Other")]
+fn unrecognised_legacy_link() {
+    let mut s = setup();
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.submit_project(
+        s.hackathon_id,
+        s.team_id,
+        vec![s.category_id],
+        "project".to_string(),
+        "description".to_string(),
+        "image".to_string(),
+        vec!["see README!".to_string()],
+        BUILDING,
+        None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "is not a valid SourceRepo")]
+fn artifact_url_without_scheme() {
+    artifact(ArtifactKind::SourceRepo, "ftp://github.com/near/project").assert_valid();
+}
+
+#[test]
+#[should_panic(expected = "is not a valid ContractAccount")]
+fn artifact_invalid_account() {
+    artifact(ArtifactKind::ContractAccount, "Not An Account").assert_valid();
+}
+
+#[test]
+#[should_panic(expected = "is not a valid IpfsCid")]
+fn artifact_invalid_cid() {
+    artifact(ArtifactKind::IpfsCid, "Qm0000").assert_valid();
+}
+
+#[test]
+#[should_panic(expected = "is not a valid Other")]
+fn other_artifact_without_https() {
+    artifact(ArtifactKind::Other, "https://near.org/project").assert_valid();
+    artifact(ArtifactKind::Other, "http://near.org/project").assert_valid();
+}
+
+#[test]
+#[should_panic(expected = "longer than 512 characters")]
+fn other_artifact_too_long() {
+    let value = format!("https://near.org/{}", "a".repeat(512));
+    artifact(ArtifactKind::Other, &value).assert_valid();
+}
+
+#[test]
+fn commit_anchored_before_deadline_is_reviewed() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+    let commit = "0123456789abcdef0123456789abcdef01234567";
+
+    set_context(accounts(2), BUILDING, 0);
+    s.contract.anchor_commit(submission_id, commit.to_string());
+
+    set_context(accounts(0), AFTER_END, 0);
+    let revision = s.contract.get_deadline_revision(submission_id).unwrap();
+    assert_eq!(revision.number, 1);
+    assert_eq!(revision.commit.unwrap().hash, commit);
+}
+
+#[test]
+#[should_panic(expected = "Invalid content sha256")]
+fn content_with_bad_hash() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.update_submission(
+        submission_id,
+        SubmissionUpdatePayload {
+            content: Some(content::ContentRef {
+                uri: "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_string(),
+                sha256: "not-a-hash".to_string(),
+            }),
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn storage_is_charged_and_refunded() {
+    let mut s = setup();
+    let before = s.contract.storage_accounts.get(&accounts(1)).unwrap();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    let after = s.contract.storage_accounts.get(&accounts(1)).unwrap();
+    assert!(after.used_bytes > before.used_bytes);
+
+    set_context(accounts(1), BUILDING, 0);
+    s.contract
+        .withdraw_submission(s.hackathon_id, submission_id);
+    let refunded = s.contract.storage_accounts.get(&accounts(1)).unwrap();
+    assert!(refunded.used_bytes < after.used_bytes);
+}
+
+fn used_bytes(s: &Setup, account_id: AccountId) -> StorageUsage {
+    s.contract
+        .storage_accounts
+        .get(&account_id)
+        .unwrap()
+        .used_bytes
+}
+
+#[test]
+fn pruned_posts_are_credited_to_their_authors() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    let author = used_bytes(&s, accounts(3));
+    s.contract
+        .post_looking_for_team(s.hackathon_id, skills(&["rust"]), "hi".to_string());
+    assert!(used_bytes(&s, accounts(3)) > author);
+    let pruner = used_bytes(&s, accounts(1));
+
+    set_context(accounts(1), AFTER_END, 0);
+    s.contract.prune_posts(s.hackathon_id);
+
+    assert_eq!(used_bytes(&s, accounts(3)), author);
+    assert_eq!(used_bytes(&s, accounts(1)), pruner);
+}
+
+#[test]
+fn posts_are_billed_the_bytes_they_store() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    let solo = used_bytes(&s, accounts(3));
+    let initial_storage = env::storage_usage();
+    s.contract
+        .post_looking_for_team(s.hackathon_id, skills(&["rust"]), "hi".to_string());
+    // The first post also pays for the key of the board
+    assert_eq!(
+        used_bytes(&s, accounts(3)) - solo,
+        env::storage_usage() - initial_storage
+    );
+
+    set_context(accounts(1), BUILDING, 0);
+    let leader = used_bytes(&s, accounts(1));
+    let initial_storage = env::storage_usage();
+    s.contract.post_looking_for_members(
+        s.team_id,
+        skills(&["design"]),
+        "a longer message".to_string(),
+    );
+    assert_eq!(
+        used_bytes(&s, accounts(1)) - leader,
+        env::storage_usage() - initial_storage
+    );
+}
+
+#[test]
+fn teammates_pay_for_nothing_they_free() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+    let submitter = used_bytes(&s, accounts(1));
+    let teammate = used_bytes(&s, accounts(2));
+
+    // A longer name is billed to the submitter, the revision to its editor
+    set_context(accounts(2), BUILDING, 0);
+    let payload = serde_json::from_value(json!({ "name": "a longer project name" })).unwrap();
+    s.contract.update_submission(submission_id, payload);
+    assert!(used_bytes(&s, accounts(1)) > submitter);
+    assert!(used_bytes(&s, accounts(2)) > teammate);
+
+    s.contract
+        .withdraw_submission(s.hackathon_id, submission_id);
+    assert!(used_bytes(&s, accounts(1)) < submitter);
+    assert_eq!(used_bytes(&s, accounts(2)), teammate);
+}
+
+#[test]
+fn deleted_team_is_credited_to_its_payer() {
+    let mut s = setup();
+    let leader = used_bytes(&s, accounts(1));
+    let member = used_bytes(&s, accounts(2));
+
+    // The leader paid for the team and hands it over when leaving
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.leave_team(s.team_id);
+    let team = s.contract.teams.get(&s.team_id).unwrap();
+    assert_eq!(team.payer, Some(accounts(2)));
+    assert!(used_bytes(&s, accounts(1)) < leader);
+    let handed_over = used_bytes(&s, accounts(2));
+    assert!(handed_over > member);
+
+    let left = used_bytes(&s, accounts(1));
+    set_context(accounts(2), BUILDING, 0);
+    s.contract.leave_team(s.team_id);
+    assert!(s.contract.teams.get(&s.team_id).is_none());
+    assert_eq!(used_bytes(&s, accounts(2)), member);
+    assert_eq!(used_bytes(&s, accounts(1)), left);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient storage balance of bob")]
+fn payer_must_cover_changes_by_teammates() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(1), BUILDING, 1);
+    s.contract.storage_withdraw(None);
+    set_context(accounts(2), BUILDING, 0);
+    let payload = serde_json::from_value(json!({ "name": "a longer project name" })).unwrap();
+    s.contract.update_submission(submission_id, payload);
+}
+
+#[test]
+#[should_panic(expected = "Storage not registered")]
+fn unregistered_account_cannot_write() {
+    let mut s = setup();
+    set_context(accounts(4), 0, 0);
+    s.contract.add_member("eve".to_string(), None, None);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient storage balance")]
+fn storage_deposit_must_cover_usage() {
+    let mut s = setup();
+    let min = s.contract.storage_balance_bounds().min.0;
+    set_context(accounts(4), 0, min);
+    s.contract.storage_deposit(None, Some(true));
+    set_context(accounts(4), 0, 0);
+    s.contract.add_member("eve".repeat(1_000), None, None);
+}
+
+#[test]
+fn storage_withdraw_keeps_used_balance() {
+    let mut s = setup();
+    set_context(accounts(3), 0, 1);
+    let balance = s.contract.storage_withdraw(None);
+
+    let account = s.contract.storage_accounts.get(&accounts(3)).unwrap();
+    assert_eq!(balance.available, U128(0));
+    assert_eq!(balance.total, U128(account.used()));
+}
+
+#[test]
+fn list_views_are_paged() {
+    let mut s = setup();
+    let (other_id, _, _) = s.other_hackathon();
+
+    let page = s.contract.get_all_hackathons(Some(1), Some(5));
+    assert_eq!(page.total, 2);
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].as_ref().unwrap().hackathon.id, other_id);
+
+    let page = s
+        .contract
+        .get_hackathon_participants(s.hackathon_id, None, Some(1));
+    assert_eq!(page.total, 2);
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].id, accounts(1));
+
+    let page = s
+        .contract
+        .get_member_created_hackathons(accounts(0), Some(5), None);
+    assert_eq!(page.total, 2);
+    assert!(page.items.is_empty());
+}
+
+#[test]
+fn page_limit_is_capped() {
+    let range = page::page_range(1_000, Some(10), Some(1_000));
+    assert_eq!(range, 10..10 + page::MAX_PAGE_LIMIT);
+}
+
+#[test]
+fn hackathon_json_keeps_inline_lists() {
+    let s = setup();
+    let hackathon = s.contract.get_hackathon(s.hackathon_id);
+    let json = serde_json::to_value(&hackathon).unwrap();
+
+    assert_eq!(
+        json["hackathon"]["participants_list"],
+        json!([accounts(1), accounts(2)])
+    );
+    assert_eq!(json["hackathon"]["categories_list"], json!([s.category_id]));
+    assert!(s.contract.get_hackathon(s.hackathon_id + 1).is_none());
+}
+
+#[test]
+fn summaries_carry_counts_only() {
+    let s = setup();
+    let page = s.contract.get_hackathon_summaries(None, None);
+    let json = serde_json::to_value(&page.items[0]).unwrap();
+
+    assert_eq!(json["participants_count"], json!(2));
+    assert_eq!(json["categories_count"], json!(1));
+    assert!(json.get("participants_list").is_none());
+}
+
+#[test]
+fn existing_views_keep_every_field() {
+    let s = setup();
+    let page = s.contract.get_all_hackathons(None, None);
+    let json = serde_json::to_value(&page.items[0]).unwrap();
+    assert_eq!(
+        json["hackathon"]["participants_list"],
+        json!([accounts(1), accounts(2)])
+    );
+    assert_eq!(json["total_prize"], json!(ONE_NEAR.to_string()));
+
+    let member = s.contract.get_user_information_by_id(accounts(1)).unwrap();
+    let json = serde_json::to_value(&member.joined_hackathons[0]).unwrap();
+    assert_eq!(json["hackathon"]["id"], json!(s.hackathon_id));
+    assert_eq!(json["hackathon"]["categories_list"], json!([s.category_id]));
+}
+
+#[test]
+fn hackathon_summary_counts_lists() {
+    let s = setup();
+    set_context(accounts(0), BUILDING, 0);
+    let summary = s.contract.get_hackathon_summary(s.hackathon_id).unwrap();
+
+    assert_eq!(summary.phase, HackathonPhase::Building);
+    assert_eq!(summary.participants_count, 2);
+    assert_eq!(summary.submissions_count, 0);
+    assert_eq!(summary.categories_count, 1);
+    assert_eq!(summary.teams_count, 1);
+    assert_eq!(summary.total_prize, U128(ONE_NEAR));
+    assert_eq!(s.contract.get_hackathon_summaries(None, None).total, 1);
+    assert!(s
+        .contract
+        .get_hackathon_summary(s.hackathon_id + 1)
+        .is_none());
+}
+
+#[test]
+fn detail_view_skips_missing_entities() {
+    let mut s = setup();
+    s.contract.members.remove(&accounts(1));
+    s.contract.awards.remove(&s.award_id);
+
+    let detail = s
+        .contract
+        .get_detail_hackathon_by_id(s.hackathon_id)
+        .unwrap();
+    assert_eq!(detail.participants.len(), 1);
+    assert!(detail.categories[0].awards.is_empty());
+    let categories = s
+        .contract
+        .get_hackathon_categories(s.hackathon_id, None, None);
+    assert_eq!(categories.total, 1);
+    assert!(s
+        .contract
+        .get_detail_hackathon_by_id(s.hackathon_id + 1)
+        .is_none());
+}
+
+#[test]
+fn hackathons_are_indexed_by_tag_and_start() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    let other_id = s.contract.add_hackathon(hackathon_payload("o")).unwrap();
+
+    let near = s
+        .contract
+        .get_hackathons_by_tag("near".to_string(), None, None);
+    assert_eq!(near.total, 2);
+    assert_eq!(
+        s.contract
+            .get_hackathons_in_range(START, END, None, Some(1))
+            .items[0]
+            .id,
+        s.hackathon_id
+    );
+
+    let payload = serde_json::from_value(json!({ "tags": ["rust"], "start": START + 1 })).unwrap();
+    s.contract.update_hackathon(s.hackathon_id, payload);
+
+    let near = s
+        .contract
+        .get_hackathons_by_tag("near".to_string(), None, None);
+    assert_eq!(near.total, 1);
+    assert_eq!(near.items[0].id, other_id);
+    let rust = s
+        .contract
+        .get_hackathons_by_tag("rust".to_string(), None, None);
+    assert_eq!(rust.items[0].id, s.hackathon_id);
+    let starting = s
+        .contract
+        .get_hackathons_in_range(START + 1, END, None, None);
+    assert_eq!(starting.items.len(), 1);
+    assert!(starting.next.is_none());
+    assert_eq!(starting.items[0].id, s.hackathon_id);
+    assert_eq!(
+        s.contract
+            .get_hackathons_in_range(END, START, None, None)
+            .items
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn cancel_hackathon_refunds_escrow_and_drops_indexes() {
+    let mut s = setup();
+    set_context(accounts(0), 0, ONE_NEAR / 2);
+    s.contract
+        .fund_award(s.hackathon_id, s.category_id, s.award_id);
+
+    set_context(accounts(0), 0, 0);
+    s.contract.cancel_hackathon(s.hackathon_id);
+
+    let summary = s.contract.get_hackathon_summary(s.hackathon_id).unwrap();
+    assert_eq!(summary.phase, HackathonPhase::Cancelled);
+    assert_eq!(s.contract.awards.get(&s.award_id).unwrap().funded, U128(0));
+    assert_eq!(
+        s.contract
+            .get_hackathons_by_tag("near".to_string(), None, None)
+            .total,
+        0
+    );
+    assert_eq!(
+        s.contract
+            .get_hackathons_in_range(0, AFTER_END, None, None)
+            .items
+            .len(),
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "Hackathon cancelled")]
+fn submit_to_cancelled_hackathon() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.cancel_hackathon(s.hackathon_id);
+    s.submit(accounts(1), vec![s.category_id]);
+}
+
+#[test]
+#[should_panic(expected = "Hackathon cancelled")]
+fn update_submission_of_cancelled_hackathon() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+    set_context(accounts(0), BUILDING, 0);
+    s.contract.cancel_hackathon(s.hackathon_id);
+
+    set_context(accounts(1), BUILDING, 0);
+    s.contract
+        .update_submission(submission_id, SubmissionUpdatePayload::default());
+}
+
+#[test]
+#[should_panic(expected = "Hackathon cancelled")]
+fn anchor_commit_of_cancelled_hackathon() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+    set_context(accounts(0), BUILDING, 0);
+    s.contract.cancel_hackathon(s.hackathon_id);
+
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.anchor_commit(
+        submission_id,
+        "0123456789abcdef0123456789abcdef01234567".to_string(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Hackathon cancelled")]
+fn add_category_to_cancelled_hackathon() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.cancel_hackathon(s.hackathon_id);
+    s.contract.add_category(s.hackathon_id, "c".to_string());
+}
+
+#[test]
+#[should_panic(expected = "Hackathon cancelled")]
+fn add_award_to_cancelled_hackathon() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.cancel_hackathon(s.hackathon_id);
+    s.contract
+        .add_award(s.hackathon_id, s.category_id, "a".to_string(), 1.0);
+}
+
+#[test]
+#[should_panic(expected = "Hackathon cancelled")]
+fn update_category_of_cancelled_hackathon() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.cancel_hackathon(s.hackathon_id);
+    s.contract
+        .update_category(s.hackathon_id, s.category_id, "renamed".to_string());
+}
+
+#[test]
+#[should_panic(expected = "Hackathon cancelled")]
+fn update_award_of_cancelled_hackathon() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.cancel_hackathon(s.hackathon_id);
+    s.contract.update_award(
+        s.hackathon_id,
+        s.category_id,
+        s.award_id,
+        Some("renamed".to_string()),
+        None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Hackathon cancelled")]
+fn add_organizer_to_cancelled_hackathon() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.cancel_hackathon(s.hackathon_id);
+    s.contract.add_organizer(s.hackathon_id, accounts(3));
+}
+
+#[test]
+fn range_pages_resume_after_the_cursor() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    let second = s.contract.add_hackathon(hackathon_payload("b")).unwrap();
+    let third = s.contract.add_hackathon(hackathon_payload("c")).unwrap();
+
+    let first = s
+        .contract
+        .get_hackathons_in_range(START, END, None, Some(2));
+    let ids: Vec<HackathonId> = first.items.iter().map(|item| item.id).collect();
+    assert_eq!(ids, vec![s.hackathon_id, second]);
+    assert_eq!(first.next, Some((START, second)));
+
+    let last = s
+        .contract
+        .get_hackathons_in_range(START, END, first.next, Some(2));
+    assert_eq!(last.items.len(), 1);
+    assert_eq!(last.items[0].id, third);
+    assert!(last.next.is_none());
+    assert!(s
+        .contract
+        .get_hackathons_in_range(START, END, Some((END, 0)), None)
+        .items
+        .is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Hackathon cancelled")]
+fn join_cancelled_hackathon() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.cancel_hackathon(s.hackathon_id);
+
+    set_context(accounts(3), 0, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+}
+
+#[test]
+fn calendar_views_bucket_by_time() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    let rich_id = s.contract.add_hackathon(hackathon_payload("r")).unwrap();
+    let category_id = s.contract.add_category(rich_id, "c".to_string()).unwrap();
+    s.contract
+        .add_award(rich_id, category_id, "a".to_string(), 2.0)
+        .unwrap();
+    let payload = serde_json::from_value(json!({
+        "name": "l",
+        "description": "description",
+        "tags": [],
+        "image": "image",
+        "start": END,
+        "end": AFTER_END,
+    }))
+    .unwrap();
+    let later_id = s.contract.add_hackathon(payload).unwrap();
+    assert_eq!(
+        s.contract
+            .get_upcoming_hackathons(None, None, None)
+            .items
+            .len(),
+        3
+    );
+
+    set_context(accounts(0), BUILDING, 0);
+    let upcoming = s.contract.get_upcoming_hackathons(None, None, None);
+    assert_eq!(upcoming.items.len(), 1);
+    assert_eq!(upcoming.items[0].id, later_id);
+    let ongoing = s.contract.get_ongoing_hackathons(None, None, None);
+    assert_eq!(ongoing.items[0].id, s.hackathon_id);
+    let ongoing = s
+        .contract
+        .get_ongoing_hackathons(Some(HackathonSort::Prize), None, Some(1));
+    assert_eq!(ongoing.items[0].id, rich_id);
+    assert_eq!(
+        ongoing.next,
+        Some(CalendarCursor::Prize(U128(2 * ONE_NEAR), rich_id))
+    );
+    assert!(s
+        .contract
+        .get_past_hackathons(None, None, None)
+        .items
+        .is_empty());
+
+    set_context(accounts(0), AFTER_END, 0);
+    let past = s.contract.get_past_hackathons(None, None, None);
+    assert_eq!(past.items.len(), 3);
+    assert_eq!(past.items[0].id, later_id);
+    assert_eq!(past.items[0].phase, HackathonPhase::Ended);
+}
+
+#[test]
+fn calendar_pages_resume_after_the_cursor() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    let second = s.contract.add_hackathon(hackathon_payload("b")).unwrap();
+    let category_id = s.contract.add_category(second, "c".to_string()).unwrap();
+    s.contract
+        .add_award(second, category_id, "a".to_string(), 2.0)
+        .unwrap();
+    let third = s.contract.add_hackathon(hackathon_payload("c")).unwrap();
+
+    set_context(accounts(0), AFTER_END, 0);
+    let ids = |page: &CursorPage<HackathonSummary, CalendarCursor>| -> Vec<HackathonId> {
+        page.items.iter().map(|item| item.id).collect()
+    };
+    let first = s.contract.get_past_hackathons(None, None, Some(2));
+    assert_eq!(ids(&first), vec![third, second]);
+    assert_eq!(first.next, Some(CalendarCursor::Start(START, second)));
+    let last = s.contract.get_past_hackathons(None, first.next, Some(2));
+    assert_eq!(ids(&last), vec![s.hackathon_id]);
+    assert!(last.next.is_none());
+
+    let sort = Some(HackathonSort::Prize);
+    let first = s.contract.get_past_hackathons(sort, None, Some(2));
+    assert_eq!(ids(&first), vec![second, s.hackathon_id]);
+    let last = s.contract.get_past_hackathons(sort, first.next, Some(2));
+    assert_eq!(ids(&last), vec![third]);
+    assert!(last.next.is_none());
+}
+
+#[test]
+fn sparse_calendar_pages_stop_after_the_scan_limit() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 10 * ONE_NEAR);
+    s.contract.storage_deposit(None, None);
+    for i in 0..page::MAX_SCAN {
+        set_context(accounts(0), 0, 0);
+        let mut payload = hackathon_payload("ended");
+        payload.start = 1 + i;
+        payload.end = 2 + i;
+        s.contract.add_hackathon(payload);
+    }
+
+    // Every hackathon that started earlier has ended, the page is empty but moves the cursor
+    set_context(accounts(0), BUILDING, 0);
+    let first = s.contract.get_ongoing_hackathons(None, None, None);
+    assert!(first.items.is_empty());
+    let last_ended = s.contract.next_hackathon_id - 1;
+    assert_eq!(
+        first.next,
+        Some(CalendarCursor::Start(page::MAX_SCAN, last_ended))
+    );
+
+    let last = s.contract.get_ongoing_hackathons(None, first.next, None);
+    assert_eq!(last.items[0].id, s.hackathon_id);
+    assert!(last.next.is_none());
+}
+
+#[test]
+fn prize_index_follows_award_changes() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    let other_id = s.contract.add_hackathon(hackathon_payload("o")).unwrap();
+    let prize_order = |s: &Setup| -> Vec<HackathonId> {
+        s.contract
+            .get_upcoming_hackathons(Some(HackathonSort::Prize), None, None)
+            .items
+            .iter()
+            .map(|item| item.id)
+            .collect()
+    };
+    assert_eq!(prize_order(&s), vec![s.hackathon_id, other_id]);
+
+    let category_id = s.contract.add_category(other_id, "c".to_string()).unwrap();
+    let award_id = s
+        .contract
+        .add_award(other_id, category_id, "a".to_string(), 2.0)
+        .unwrap();
+    assert_eq!(prize_order(&s), vec![other_id, s.hackathon_id]);
+
+    s.contract
+        .update_award(other_id, category_id, award_id, None, Some(0.5));
+    assert_eq!(prize_order(&s), vec![s.hackathon_id, other_id]);
+    assert_eq!(s.contract.hackathons_by_prize.len(), 2);
+
+    s.contract.cancel_hackathon(other_id);
+    assert_eq!(prize_order(&s), vec![s.hackathon_id]);
+    assert_eq!(s.contract.hackathons_by_prize.len(), 1);
+}
+
+#[test]
+fn prize_totals_follow_awards() {
+    let mut s = setup();
+    let totals = |s: &Setup| {
+        s.contract
+            .get_hackathon_summary(s.hackathon_id)
+            .unwrap()
+            .prizes[0]
+            .clone()
+    };
+    assert_eq!(totals(&s).promised, U128(ONE_NEAR));
+
+    set_context(accounts(0), 0, 0);
+    s.contract
+        .update_award(s.hackathon_id, s.category_id, s.award_id, None, Some(2.0));
+    assert_eq!(totals(&s).promised, U128(2 * ONE_NEAR));
+    set_context(accounts(0), 0, ONE_NEAR / 2);
+    s.contract
+        .fund_award(s.hackathon_id, s.category_id, s.award_id);
+    assert_eq!(totals(&s).funded, U128(ONE_NEAR / 2));
+
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+    set_context(accounts(0), AFTER_END, 3 * ONE_NEAR / 2);
+    s.contract
+        .award_winner(s.hackathon_id, s.category_id, s.award_id);
+
+    let totals = totals(&s);
+    assert_eq!(totals.funded, U128(2 * ONE_NEAR));
+    assert_eq!(totals.paid, U128(2 * ONE_NEAR));
+    assert!(
+        s.contract
+            .get_prize_totals_check(s.hackathon_id)
+            .unwrap()
+            .consistent
+    );
+}
+
+#[test]
+fn prize_totals_check_finds_drift() {
+    let mut s = setup();
+    let mut award = s.contract.awards.get(&s.award_id).unwrap();
+    award.price = U128(2 * ONE_NEAR);
+    s.contract.awards.insert(&s.award_id, &award);
+
+    let check = s.contract.get_prize_totals_check(s.hackathon_id).unwrap();
+    assert!(!check.consistent);
+    assert_eq!(check.cached[0].promised, U128(ONE_NEAR));
+    assert_eq!(check.computed[0].promised, U128(2 * ONE_NEAR));
+}
+
+fn last_event() -> serde_json::Value {
+    let log = get_logs().pop().unwrap();
+    serde_json::from_str(log.strip_prefix("EVENT_JSON:").unwrap()).unwrap()
+}
+
+#[test]
+fn state_changes_are_logged_as_events() {
+    let mut s = setup();
+    set_context(accounts(4), 0, ONE_NEAR);
+    s.contract.storage_deposit(None, None);
+    set_context(accounts(4), 0, 0);
+    s.contract.add_member("member 4".to_string(), None, None);
+    assert_eq!(
+        last_event(),
+        json!({
+            "standard": "hackathon",
+            "version": "1.0.0",
+            "event": "member_registered",
+            "data": { "account_id": accounts(4) },
+        })
+    );
+
+    s.contract.join_hackathon(s.hackathon_id);
+    assert_eq!(last_event()["event"], "participant_joined");
+
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+    let event = last_event();
+    assert_eq!(event["event"], "project_submitted");
+    assert_eq!(event["data"]["submission_id"], submission_id);
+    assert_eq!(
+        event["data"]["account_ids"],
+        json!([accounts(1), accounts(2)])
+    );
+
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+    assert_eq!(last_event()["event"], "winner_judged");
+    set_context(accounts(0), AFTER_END, ONE_NEAR);
+    s.contract
+        .award_winner(s.hackathon_id, s.category_id, s.award_id);
+    assert_eq!(
+        last_event()["data"],
+        json!({
+            "hackathon_id": s.hackathon_id,
+            "category_id": s.category_id,
+            "award_id": s.award_id,
+            "submission_id": submission_id,
+            "receiver_id": accounts(1),
+            "token": "Near",
+            "amount": ONE_NEAR.to_string(),
+        })
+    );
+}
+
+#[test]
+fn organizer_changes_are_logged_as_events() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    let hackathon_id = s.contract.add_hackathon(hackathon_payload("o")).unwrap();
+    assert_eq!(
+        last_event()["data"],
+        json!({
+            "hackathon_id": hackathon_id,
+            "owner_id": accounts(0),
+            "start": START,
+            "end": END,
+        })
+    );
+    assert_eq!(last_event()["event"], "hackathon_created");
+
+    let category_id = s
+        .contract
+        .add_category(hackathon_id, "c".to_string())
+        .unwrap();
+    assert_eq!(
+        last_event(),
+        json!({
+            "standard": "hackathon",
+            "version": "1.0.0",
+            "event": "category_added",
+            "data": { "hackathon_id": hackathon_id, "category_id": category_id },
+        })
+    );
+    let award_id = s
+        .contract
+        .add_award(hackathon_id, category_id, "a".to_string(), 2.0)
+        .unwrap();
+    let event = last_event();
+    assert_eq!(event["event"], "award_added");
+    assert_eq!(
+        event["data"],
+        json!({
+            "hackathon_id": hackathon_id,
+            "category_id": category_id,
+            "award_id": award_id,
+            "token": "Near",
+            "amount": (2 * ONE_NEAR).to_string(),
+        })
+    );
+
+    let payload = serde_json::from_value(json!({ "end": AFTER_END })).unwrap();
+    s.contract.update_hackathon(hackathon_id, payload);
+    let event = last_event();
+    assert_eq!(event["event"], "hackathon_updated");
+    assert_eq!(event["data"]["end"], AFTER_END);
+    s.contract
+        .update_category(hackathon_id, category_id, "renamed".to_string());
+    assert_eq!(last_event()["event"], "category_updated");
+    s.contract
+        .update_award(hackathon_id, category_id, award_id, None, Some(3.0));
+    let event = last_event();
+    assert_eq!(event["event"], "award_updated");
+    assert_eq!(event["data"]["amount"], (3 * ONE_NEAR).to_string());
+
+    set_context(accounts(0), 0, ONE_NEAR);
+    s.contract.fund_award(hackathon_id, category_id, award_id);
+    set_context(accounts(0), 0, 0);
+    s.contract.cancel_hackathon(hackathon_id);
+    assert_eq!(
+        last_event()["data"],
+        json!({ "hackathon_id": hackathon_id, "refund": ONE_NEAR.to_string() })
+    );
+}
+
+#[test]
+fn membership_changes_are_logged_as_events() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(2), BUILDING, 0);
+    s.contract.leave_team(s.team_id);
+    assert_eq!(
+        last_event(),
+        json!({
+            "standard": "hackathon",
+            "version": "1.0.0",
+            "event": "team_member_left",
+            "data": {
+                "hackathon_id": s.hackathon_id,
+                "team_id": s.team_id,
+                "account_id": accounts(2),
+                "submission_ids": [submission_id],
+            },
+        })
+    );
+
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.invite_to_team(s.team_id, accounts(2));
+    set_context(accounts(2), BUILDING, 0);
+    s.contract.accept_team_invitation(s.team_id);
+    let event = last_event();
+    assert_eq!(event["event"], "team_member_joined");
+    assert_eq!(event["data"]["submission_ids"], json!([submission_id]));
+
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    s.contract.leave_hackathon(s.hackathon_id);
+    assert_eq!(
+        last_event()["data"],
+        json!({ "hackathon_id": s.hackathon_id, "account_id": accounts(3) })
+    );
+    assert_eq!(last_event()["event"], "participant_left");
+    s.contract.join_hackathon(s.hackathon_id);
+    let team_id = s.contract.create_team(s.hackathon_id, "d".to_string());
+    assert_eq!(
+        last_event()["data"],
+        json!({ "hackathon_id": s.hackathon_id, "team_id": team_id, "account_id": accounts(3) })
+    );
+    assert_eq!(last_event()["event"], "team_created");
+
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+    set_context(accounts(1), AFTER_END, 0);
+    s.contract
+        .withdraw_submission(s.hackathon_id, submission_id);
+    assert_eq!(
+        last_event()["data"],
+        json!({
+            "hackathon_id": s.hackathon_id,
+            "submission_id": submission_id,
+            "team_id": s.team_id,
+            "award_ids": [s.award_id],
+        })
+    );
+    assert_eq!(last_event()["event"], "submission_withdrawn");
+}
+
+#[test]
+fn ownership_changes_are_logged_as_events() {
+    let mut s = setup();
+    set_context(accounts(0), 0, ONE_NEAR);
+    s.contract
+        .fund_award(s.hackathon_id, s.category_id, s.award_id);
+    assert_eq!(
+        last_event(),
+        json!({
+            "standard": "hackathon",
+            "version": "1.0.0",
+            "event": "award_funded",
+            "data": {
+                "hackathon_id": s.hackathon_id,
+                "category_id": s.category_id,
+                "award_id": s.award_id,
+                "token": "Near",
+                "amount": ONE_NEAR.to_string(),
+                "funded": ONE_NEAR.to_string(),
+            },
+        })
+    );
+
+    set_context(accounts(0), 0, 0);
+    s.contract.add_organizer(s.hackathon_id, accounts(3));
+    assert_eq!(last_event()["event"], "organizer_added");
+    assert_eq!(
+        last_event()["data"],
+        json!({ "hackathon_id": s.hackathon_id, "account_id": accounts(3) })
+    );
+    s.contract.remove_organizer(s.hackathon_id, accounts(3));
+    assert_eq!(last_event()["event"], "organizer_removed");
+    assert_eq!(
+        last_event()["data"],
+        json!({ "hackathon_id": s.hackathon_id, "account_id": accounts(3) })
+    );
+
+    s.contract.set_payout_dao(s.hackathon_id, Some(dao_id()));
+    assert_eq!(last_event()["event"], "payout_dao_set");
+    assert_eq!(
+        last_event()["data"],
+        json!({ "hackathon_id": s.hackathon_id, "dao_id": dao_id() })
+    );
+    s.contract.set_payout_dao(s.hackathon_id, None);
+    assert_eq!(last_event()["data"]["dao_id"], json!(null));
+
+    s.contract.propose_owner(s.hackathon_id, Some(accounts(3)));
+    assert_eq!(last_event()["event"], "owner_proposed");
+    assert_eq!(
+        last_event()["data"],
+        json!({ "hackathon_id": s.hackathon_id, "owner_id": accounts(3) })
+    );
+    set_context(accounts(3), 0, 0);
+    s.contract.accept_owner(s.hackathon_id);
+    assert_eq!(
+        last_event(),
+        json!({
+            "standard": "hackathon",
+            "version": "1.0.0",
+            "event": "owner_accepted",
+            "data": {
+                "hackathon_id": s.hackathon_id,
+                "old_owner_id": accounts(0),
+                "owner_id": accounts(3),
+            },
+        })
+    );
+}
+
+#[test]
+fn submission_edits_are_logged_as_events() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+
+    set_context(accounts(2), BUILDING, 0);
+    let payload = serde_json::from_value(json!({ "name": "renamed" })).unwrap();
+    s.contract.update_submission(submission_id, payload);
+    assert_eq!(
+        last_event(),
+        json!({
+            "standard": "hackathon",
+            "version": "1.0.0",
+            "event": "submission_updated",
+            "data": {
+                "hackathon_id": s.hackathon_id,
+                "submission_id": submission_id,
+                "revision": 1,
+            },
+        })
+    );
+
+    let commit = "0123456789abcdef0123456789abcdef01234567";
+    s.contract.anchor_commit(submission_id, commit.to_string());
+    assert_eq!(last_event()["event"], "commit_anchored");
+    assert_eq!(
+        last_event()["data"],
+        json!({
+            "hackathon_id": s.hackathon_id,
+            "submission_id": submission_id,
+            "revision": 2,
+            "commit_hash": commit,
+        })
+    );
+}
+
+#[test]
+fn invitations_and_posts_are_logged_as_events() {
+    let mut s = setup();
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.join_hackathon(s.hackathon_id);
+    s.contract
+        .post_looking_for_team(s.hackathon_id, vec![], "hi".to_string());
+    assert_eq!(
+        last_event(),
+        json!({
+            "standard": "hackathon",
+            "version": "1.0.0",
+            "event": "post_added",
+            "data": {
+                "hackathon_id": s.hackathon_id,
+                "author": accounts(3),
+                "team_id": null,
+            },
+        })
+    );
+
+    // A new post replaces the old one of its author
+    set_context(accounts(3), BUILDING, 0);
+    s.contract
+        .post_looking_for_team(s.hackathon_id, vec![], "hello".to_string());
+    let events: Vec<String> = get_logs()
+        .iter()
+        .map(|log| {
+            let event: serde_json::Value =
+                serde_json::from_str(log.strip_prefix("EVENT_JSON:").unwrap()).unwrap();
+            event["event"].as_str().unwrap().to_string()
+        })
+        .collect();
+    assert_eq!(events, vec!["post_removed", "post_added"]);
+
+    set_context(accounts(1), BUILDING, 0);
+    s.contract.invite_to_team(s.team_id, accounts(3));
+    assert_eq!(last_event()["event"], "team_member_invited");
+    assert_eq!(
+        last_event()["data"],
+        json!({ "hackathon_id": s.hackathon_id, "team_id": s.team_id, "account_id": accounts(3) })
+    );
+    s.contract
+        .post_looking_for_members(s.team_id, vec![], "join".to_string());
+    assert_eq!(
+        last_event()["data"],
+        json!({ "hackathon_id": s.hackathon_id, "author": accounts(1), "team_id": s.team_id })
+    );
+
+    set_context(accounts(3), BUILDING, 0);
+    s.contract.decline_team_invitation(s.team_id);
+    assert_eq!(last_event()["event"], "team_invitation_declined");
+    assert_eq!(
+        last_event()["data"],
+        json!({ "hackathon_id": s.hackathon_id, "team_id": s.team_id, "account_id": accounts(3) })
+    );
+    s.contract.remove_post(s.hackathon_id, None);
+    assert_eq!(last_event()["event"], "post_removed");
+    assert_eq!(
+        last_event()["data"],
+        json!({ "hackathon_id": s.hackathon_id, "author": accounts(3), "team_id": null })
+    );
+}
+
+// Run one call in a context of its own and keep the events it logged
+fn logged<T>(
+    log: &mut Vec<String>,
+    contract: &mut HackathonContract,
+    signer: AccountId,
+    time: Timestamp,
+    deposit: Balance,
+    call: impl FnOnce(&mut HackathonContract) -> T,
+) -> T {
+    set_context(signer, time, deposit);
+    let result = call(contract);
+    log.extend(get_logs());
+    result
+}
+
+// Compare a log with a fixture of the indexer, `UPDATE_FIXTURES=1` writes the log instead
+fn assert_fixture(name: &str, log: &[String]) {
+    let path = format!(
+        "{}/indexer/tests/fixtures/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    );
+    let lines: String = log.iter().map(|line| format!("{}\n", line)).collect();
+    if std::env::var_os("UPDATE_FIXTURES").is_some() {
+        std::fs::write(&path, lines).unwrap();
+    } else {
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            lines,
+            "{} is out of date, run the test with UPDATE_FIXTURES=1",
+            name
+        );
+    }
+}
+
+// The replay tests of the indexer read the events of this run: `events.log` while the hackathon
+// is building, `removals.log` for what follows up to the payout and an ownership transfer
+#[test]
+fn indexer_fixtures_are_contract_logs() {
+    let mut log = Vec::new();
+    set_context(accounts(0), 0, 0);
+    let mut c = HackathonContract::init(None, None);
+    for i in 0..5 {
+        set_context(accounts(i), 0, ONE_NEAR);
+        c.storage_deposit(None, None);
+        logged(&mut log, &mut c, accounts(i), 0, 0, |c| {
+            c.add_member(format!("member {}", i), None, None)
+        });
+    }
+
+    let hackathon_id = logged(&mut log, &mut c, accounts(0), 0, 0, |c| {
+        c.add_hackathon(hackathon_payload("h")).unwrap()
+    });
+    let category_id = logged(&mut log, &mut c, accounts(0), 0, 0, |c| {
+        c.add_category(hackathon_id, "c".to_string()).unwrap()
+    });
+    let first = logged(&mut log, &mut c, accounts(0), 0, 0, |c| {
+        c.add_award(hackathon_id, category_id, "first".to_string(), 1.0)
+            .unwrap()
+    });
+    let second = logged(&mut log, &mut c, accounts(0), 0, 0, |c| {
+        c.add_award(hackathon_id, category_id, "second".to_string(), 0.5)
+            .unwrap()
+    });
+    logged(&mut log, &mut c, accounts(0), 0, ONE_NEAR / 2, |c| {
+        c.fund_award(hackathon_id, category_id, second)
+    });
+    logged(&mut log, &mut c, accounts(0), 0, 0, |c| {
+        c.add_organizer(hackathon_id, accounts(3))
+    });
+    for i in [1, 2, 4] {
+        logged(&mut log, &mut c, accounts(i), 0, 0, |c| {
+            c.join_hackathon(hackathon_id)
+        });
+    }
+
+    let submit = |c: &mut HackathonContract, team_id| {
+        let submission_id = c.next_submission_id;
+        c.submit_project(
+            hackathon_id,
+            team_id,
+            vec![category_id],
+            "project".to_string(),
+            "description".to_string(),
+            "image".to_string(),
+            vec!["https://example.com".to_string()],
+            BUILDING,
+            None,
+        );
+        submission_id
+    };
+    let team_id = logged(&mut log, &mut c, accounts(1), BUILDING, 0, |c| {
+        c.create_team(hackathon_id, "t".to_string())
+    });
+    logged(&mut log, &mut c, accounts(1), BUILDING, 0, |c| {
+        c.invite_to_team(team_id, accounts(2))
+    });
+    logged(&mut log, &mut c, accounts(2), BUILDING, 0, |c| {
+        c.accept_team_invitation(team_id)
+    });
+    let winner = logged(&mut log, &mut c, accounts(1), BUILDING, 0, |c| {
+        submit(c, team_id)
+    });
+    let other_team_id = logged(&mut log, &mut c, accounts(4), BUILDING, 0, |c| {
+        c.create_team(hackathon_id, "u".to_string())
+    });
+    let withdrawn = logged(&mut log, &mut c, accounts(4), BUILDING, 0, |c| {
+        submit(c, other_team_id)
+    });
+    logged(&mut log, &mut c, accounts(1), BUILDING, 0, |c| {
+        c.post_looking_for_members(team_id, vec![], "join".to_string())
+    });
+    assert_fixture("events.log", &log);
+
+    let mut log = Vec::new();
+    let payload = serde_json::from_value(json!({ "end": 15_000 })).unwrap();
+    logged(&mut log, &mut c, accounts(0), BUILDING, 0, |c| {
+        c.update_hackathon(hackathon_id, payload)
+    });
+    logged(&mut log, &mut c, accounts(0), BUILDING, 0, |c| {
+        c.update_award(hackathon_id, category_id, second, None, Some(2.0))
+    });
+    logged(&mut log, &mut c, accounts(0), BUILDING, 0, |c| {
+        c.remove_organizer(hackathon_id, accounts(3))
+    });
+    logged(&mut log, &mut c, accounts(2), BUILDING, 0, |c| {
+        c.leave_team(team_id)
+    });
+
+    let cancelled_id = logged(&mut log, &mut c, accounts(0), BUILDING, 0, |c| {
+        c.add_hackathon(hackathon_payload("cancelled")).unwrap()
+    });
+    let cancelled_category_id = logged(&mut log, &mut c, accounts(0), BUILDING, 0, |c| {
+        c.add_category(cancelled_id, "c".to_string()).unwrap()
+    });
+    let refunded = logged(&mut log, &mut c, accounts(0), BUILDING, 0, |c| {
+        c.add_award(cancelled_id, cancelled_category_id, "a".to_string(), 1.0)
+            .unwrap()
+    });
+    logged(&mut log, &mut c, accounts(0), BUILDING, ONE_NEAR, |c| {
+        c.fund_award(cancelled_id, cancelled_category_id, refunded)
+    });
+    logged(&mut log, &mut c, accounts(0), BUILDING, 0, |c| {
+        c.cancel_hackathon(cancelled_id)
+    });
+
+    logged(&mut log, &mut c, accounts(0), BUILDING, 0, |c| {
+        c.set_payout_dao(hackathon_id, Some(dao_id()))
+    });
+    logged(&mut log, &mut c, accounts(0), AFTER_END, 0, |c| {
+        c.judge_winner(hackathon_id, category_id, first, winner)
+    });
+    logged(&mut log, &mut c, accounts(0), AFTER_END, 0, |c| {
+        c.judge_winner(hackathon_id, category_id, second, withdrawn)
+    });
+    logged(&mut log, &mut c, accounts(4), AFTER_END, 0, |c| {
+        c.withdraw_submission(hackathon_id, withdrawn)
+    });
+
+    // The first proposal is rejected, the second one pays
+    for (proposal_id, status) in [(7, ProposalStatus::Rejected), (8, ProposalStatus::Approved)] {
+        logged(&mut log, &mut c, accounts(0), AFTER_END, BOND, |c| {
+            c.propose_award_payout(hackathon_id, category_id, first)
+        });
+        set_contract_context(env::current_account_id(), accounts(0), 0);
+        c.on_payout_proposed(
+            hackathon_id,
+            category_id,
+            first,
+            accounts(0),
+            U128(BOND),
+            Ok(proposal_id),
+        );
+        log.extend(get_logs());
+        set_contract_context(env::current_account_id(), accounts(3), 0);
+        c.on_payout_checked(
+            hackathon_id,
+            category_id,
+            first,
+            proposal_id,
+            Ok(Proposal { status }),
+        );
+        log.extend(get_logs());
+    }
+
+    logged(&mut log, &mut c, accounts(0), AFTER_END, 0, |c| {
+        c.propose_owner(hackathon_id, Some(accounts(3)))
+    });
+    logged(&mut log, &mut c, accounts(3), AFTER_END, 0, |c| {
+        c.accept_owner(hackathon_id)
+    });
+    assert_fixture("removals.log", &log);
+}
+
+fn write_legacy<K: BorshSerialize, V: BorshSerialize>(prefix: &[u8], key: &K, value: &V) {
+    let key = [prefix.to_vec(), key.try_to_vec().unwrap()].concat();
+    env::storage_write(&key, &value.try_to_vec().unwrap());
+}
+
+// State as the first deployment wrote it: untagged entities and the original contract fields.
+// bob submitted project 0 to hackathon 0 by alice, which has category 0 with award 0.
+fn legacy_state() {
+    set_context(accounts(0), 0, 0);
+    for i in 0..3 {
+        write_legacy(
+            b"members",
+            &accounts(i),
+            &member::MemberV0 {
+                id: accounts(i),
+                name: format!("member {}", i),
+                image: None,
+                bio: None,
+                joined_hackathons: if i == 1 { vec![0] } else { Vec::new() },
+                created_hackathons: if i == 0 { vec![0] } else { Vec::new() },
+            },
+        );
+    }
+    write_legacy(
+        b"hackathons",
+        &0u64,
+        &hackathon::HackathonV0 {
+            owner: accounts(0),
+            id: 0,
+            name: "h".to_string(),
+            description: "description".to_string(),
+            image: "image".to_string(),
+            start: START,
+            end: END,
+            tags: vec!["near".to_string()],
+            participants_list: vec![accounts(1)],
+            submissions_list: vec![0],
+            categories_list: vec![0],
+        },
+    );
+    write_legacy(
+        b"submissions",
+        &0u64,
+        &submission::SubmissionV0 {
+            id: 0,
+            name: "project".to_string(),
+            description: "description".to_string(),
+            image: "image".to_string(),
+            time: BUILDING,
+            link: vec!["https://github.com/near/project".to_string()],
+            categories: vec![0],
+            members: vec![accounts(1)],
+        },
+    );
+    write_legacy(
+        b"categories",
+        &0u64,
+        &Category {
+            id: 0,
+            name: "c".to_string(),
+            awards: vec![0],
+        },
+    );
+    write_legacy(
+        b"awards",
+        &0u64,
+        &award::AwardV0 {
+            id: 0,
+            name: "a".to_string(),
+            price: U128(near_to_yocto(1.0)),
+            winner: None,
+            is_awarded: false,
+        },
+    );
+
+    // Both lists were created with the prefix "m", hackathon 0 overwrote the slot of alice
+    let mut members_list = UnorderedSet::new(b"m".to_vec());
+    for i in 0..3 {
+        members_list.insert(&accounts(i));
+    }
+    let mut hackathons_list = UnorderedSet::new(b"m".to_vec());
+    hackathons_list.insert(&0u64);
+    env::state_write(&version::HackathonContractV0 {
+        members_list,
+        hackathons_list,
+        members: VersionedMap::new(b"members".to_vec()),
+        awards: VersionedMap::new(b"awards".to_vec()),
+        hackathons: VersionedMap::new(b"hackathons".to_vec()),
+        submissions: VersionedMap::new(b"submissions".to_vec()),
+        categories: VersionedMap::new(b"categories".to_vec()),
+        next_submission_id: 1,
+        next_category_id: 1,
+        next_award_id: 1,
+        next_hackathon_id: 1,
+    });
+}
+
+// Run `migrate` one entity per call, as the contract account, until nothing is pending
+fn migrate_all() -> (HackathonContract, u32) {
+    testing_env!(VMContextBuilder::new()
+        .predecessor_account_id(env::current_account_id())
+        .block_timestamp(BUILDING)
+        .build());
+    let mut calls = 0;
+    loop {
+        let contract = HackathonContract::migrate(Some(1));
+        calls += 1;
+        env::state_write(&contract);
+        if contract.get_state_version().migration.is_none() {
+            return (contract, calls);
+        }
+    }
+}
+
+#[test]
+fn migrate_legacy_state() {
+    legacy_state();
+    let (mut contract, calls) = migrate_all();
+    // One call per hackathon, category, award and slot of the shared list
+    assert_eq!(calls, 6);
+    assert_eq!(contract.get_state_version().version, STATE_VERSION);
+
+    let hackathon = contract.hackathons.get(&0).unwrap();
+    assert!(hackathon.participants_list.contains(&accounts(1)));
+    assert_eq!(
+        contract
+            .get_hackathons_in_range(START, START + 1, None, None)
+            .items
+            .len(),
+        1
+    );
+    assert!(contract.get_prize_totals_check(0).unwrap().consistent);
+    assert_eq!(
+        contract.hackathons_by_prize.to_vec(),
+        vec![(hackathon.prize_key(), ())]
+    );
+    assert_eq!(hackathon.teams_list, vec![0]);
+
+    let submission = contract.submissions.get(&0).unwrap();
+    assert_eq!(submission.team_id, 0);
+    assert_eq!(submission.artifacts[0].kind, ArtifactKind::SourceRepo);
+    let team = contract.teams.get(&0).unwrap();
+    assert_eq!(team.leader, accounts(1));
+    assert_eq!(team.submissions, vec![0]);
+    assert_eq!(contract.members.get(&accounts(1)).unwrap().teams, vec![0]);
+
+    let award = contract.awards.get(&0).unwrap();
+    assert_eq!(award.funded, U128(0));
+    assert_eq!(contract.categories.get(&0).unwrap().awards, vec![0]);
+
+    // The migrated hackathon works with the current calls
+    set_context(accounts(2), BUILDING, ONE_NEAR);
+    contract.storage_deposit(None, None);
+    set_context(accounts(2), BUILDING, 0);
+    contract.join_hackathon(0);
+    assert_eq!(contract.get_hackathon_participants(0, None, None).total, 2);
+}
+
+#[test]
+fn migrated_entities_round_trip() {
+    legacy_state();
+    let (contract, _) = migrate_all();
+
+    // Stored values are the versioned enums, tagged with the current variant
+    let raw =
+        env::storage_read(&[b"hackathons".to_vec(), 0u64.try_to_vec().unwrap()].concat()).unwrap();
+    assert!(matches!(
+        VersionedHackathon::try_from_slice(&raw).unwrap(),
+        VersionedHackathon::V1(_)
+    ));
+    let raw =
+        env::storage_read(&[b"awards".to_vec(), 0u64.try_to_vec().unwrap()].concat()).unwrap();
+    let award = match VersionedAward::try_from_slice(&raw).unwrap() {
+        VersionedAward::V1(award) => award,
+        _ => panic!("award not upgraded"),
+    };
+    assert_eq!(VersionedAward::V1(award).try_to_vec().unwrap(), raw);
+
+    // Members nobody wrote since are still read from the untagged layout
+    let member = contract.get_user_information_by_id(accounts(0)).unwrap();
+    assert_eq!(member.created_hackathons[0].hackathon.id, 0);
+}
+
+#[test]
+fn migrated_teams_are_paid_by_the_caller() {
+    legacy_state();
+    let (mut contract, _) = migrate_all();
+    assert_eq!(contract.teams.get(&0).unwrap().payer, None);
+    assert_eq!(contract.submissions.get(&0).unwrap().payer, None);
+
+    // Nobody paid for the migrated submission and team, so freeing them credits nothing back
+    set_context(accounts(1), BUILDING, ONE_NEAR);
+    contract.storage_deposit(None, None);
+    let used = contract
+        .storage_accounts
+        .get(&accounts(1))
+        .unwrap()
+        .used_bytes;
+    set_context(accounts(1), BUILDING, 0);
+    contract.withdraw_submission(0, 0);
+    contract.leave_team(0);
+    assert!(
+        contract
+            .storage_accounts
+            .get(&accounts(1))
+            .unwrap()
+            .used_bytes
+            >= used
+    );
+}
+
+#[test]
+#[should_panic(expected = "Contract state is being migrated")]
+fn calls_wait_for_migration() {
+    legacy_state();
+    testing_env!(VMContextBuilder::new()
+        .predecessor_account_id(env::current_account_id())
+        .build());
+    let mut contract = HackathonContract::migrate(Some(1));
+
+    set_context(accounts(4), 0, 0);
+    contract.add_member("eve".to_string(), None, None);
+}
+
+#[test]
+fn migrate_current_state_is_a_no_op() {
+    let s = setup();
+    env::state_write(&s.contract);
+    let (contract, calls) = migrate_all();
+
+    assert_eq!(calls, 1);
+    assert_eq!(contract.next_hackathon_id, s.contract.next_hackathon_id);
+    assert!(contract.hackathons.get(&s.hackathon_id).is_some());
+}
+
+#[test]
+fn member_and_hackathon_lists_are_separate() {
+    let s = setup();
+
+    let members = s.contract.get_all_members(None, None);
+    assert_eq!(members.total, 4);
+    assert_eq!(members.items[0].id, accounts(0));
+    let hackathons = s.contract.get_all_hackathons(None, None);
+    assert_eq!(hackathons.total, 1);
+    assert_eq!(
+        hackathons.items[0].as_ref().unwrap().hackathon.id,
+        s.hackathon_id
+    );
+}
+
+#[test]
+fn migrate_repairs_shared_list() {
+    legacy_state();
+    let (mut contract, _) = migrate_all();
+
+    // alice is found as the owner of hackathon 0, bob as its participant, charlie in slot 2
+    let members: Vec<AccountId> = contract
+        .get_all_members(None, None)
+        .items
+        .into_iter()
+        .map(|member| member.id)
+        .collect();
+    assert_eq!(members, vec![accounts(0), accounts(1), accounts(2)]);
+    assert_eq!(contract.get_all_hackathons(None, None).total, 1);
+    assert!(!env::storage_has_key(&version::legacy_list_slot(0)));
+    assert!(!env::storage_has_key(&version::legacy_list_index(
+        &accounts(0).try_to_vec().unwrap()
+    )));
+
+    // Members only the owner knows about are added back explicitly, others are ignored
+    write_legacy(
+        b"members",
+        &accounts(3),
+        &member::MemberV0 {
+            id: accounts(3),
+            name: "member 3".to_string(),
+            image: None,
+            bio: None,
+            joined_hackathons: Vec::new(),
+            created_hackathons: Vec::new(),
+        },
+    );
+    contract.repair_members_list(vec![accounts(3), accounts(4)]);
+    assert_eq!(contract.get_all_members(None, None).total, 4);
+}
+fn code_hash(code: &[u8]) -> Base58CryptoHash {
+    Base58CryptoHash::from(<[u8; 32]>::try_from(env::sha256(code)).unwrap())
+}
+
+#[test]
+fn upgrade_waits_for_the_timelock() {
+    set_context(accounts(0), 0, 0);
+    let mut contract = HackathonContract::init(None, Some(U64(100)));
+    assert_eq!(contract.get_contract_owner(), accounts(0));
+
+    contract.stage_upgrade(code_hash(b"code"));
+    let staged = contract.get_staged_upgrade().unwrap();
+    assert_eq!(staged.deployable_at, 100);
+
+    set_context(accounts(0), 100, 0);
+    contract.deploy_upgrade(b"code".to_vec());
+    // Cleared by the callback once the code is deployed and migrated
+    assert_eq!(contract.get_staged_upgrade(), Some(staged));
+    let methods: Vec<String> = get_created_receipts()
+        .into_iter()
+        .flat_map(|receipt| receipt.actions)
+        .filter_map(|action| match action {
+            VmAction::FunctionCall { function_name, .. } => Some(function_name),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(methods, vec!["migrate", "on_upgrade_deployed"]);
+
+    upgrade_deployed(&mut contract, PromiseResult::Successful(Vec::new()));
+    assert!(contract.get_staged_upgrade().is_none());
+}
+
+// Run the callback of the deploy and `migrate` batch with its result
+fn upgrade_deployed(contract: &mut HackathonContract, result: PromiseResult) -> bool {
+    testing_env!(
+        VMContextBuilder::new()
+            .predecessor_account_id(env::current_account_id())
+            .build(),
+        VMConfig::test(),
+        RuntimeFeesConfig::test(),
+        Default::default(),
+        vec![result],
+    );
+    contract.on_upgrade_deployed(code_hash(b"code"))
+}
+
+#[test]
+fn failed_upgrade_stays_staged() {
+    set_context(accounts(0), 0, 0);
+    let mut contract = HackathonContract::init(None, Some(U64(100)));
+    contract.stage_upgrade(code_hash(b"code"));
+
+    set_context(accounts(0), 100, 0);
+    contract.deploy_upgrade(b"code".to_vec());
+    assert!(!upgrade_deployed(&mut contract, PromiseResult::Failed));
+    assert!(contract.get_staged_upgrade().is_some());
+
+    // The owner retries with the same code
+    set_context(accounts(0), 100, 0);
+    contract.deploy_upgrade(b"code".to_vec());
+    assert!(upgrade_deployed(
+        &mut contract,
+        PromiseResult::Successful(Vec::new())
+    ));
+    assert!(contract.get_staged_upgrade().is_none());
+}
+
+#[test]
+#[should_panic(expected = "Upgrade is timelocked until 100")]
+fn upgrade_before_the_timelock_panics() {
+    set_context(accounts(0), 0, 0);
+    let mut contract = HackathonContract::init(None, Some(U64(100)));
+    contract.stage_upgrade(code_hash(b"code"));
+    set_context(accounts(0), 99, 0);
+    contract.deploy_upgrade(b"code".to_vec());
+}
+
+#[test]
+#[should_panic(expected = "Code does not match the staged upgrade")]
+fn upgrade_with_other_code_panics() {
+    set_context(accounts(0), 0, 0);
+    let mut contract = HackathonContract::init(None, Some(U64(100)));
+    contract.stage_upgrade(code_hash(b"code"));
+    set_context(accounts(0), 100, 0);
+    contract.deploy_upgrade(b"other code".to_vec());
+}
+
+#[test]
+#[should_panic(expected = "Upgrade must be staged first")]
+fn timelocked_upgrade_must_be_staged() {
+    set_context(accounts(0), 0, 0);
+    let contract = HackathonContract::init(None, Some(U64(100)));
+    contract.deploy_upgrade(b"code".to_vec());
+}
+
+#[test]
+fn upgrade_without_timelock_needs_no_staging() {
+    let s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.deploy_upgrade(b"code".to_vec());
+}
+
+#[test]
+#[should_panic(expected = "Not owner")]
+fn upgrade_by_other_account_panics() {
+    let s = setup();
+    set_context(accounts(1), 0, 0);
+    s.contract.deploy_upgrade(b"code".to_vec());
+}
+
+#[test]
+#[should_panic(expected = "Upgrade delay can only be raised")]
+fn upgrade_delay_cannot_be_lowered() {
+    set_context(accounts(0), 0, 0);
+    let mut contract = HackathonContract::init(None, Some(U64(100)));
+    contract.set_upgrade_delay(U64(50));
+}
+
+#[test]
+fn contract_owner_is_transferred_in_two_steps() {
+    let mut s = setup();
+    set_context(accounts(0), 0, 0);
+    s.contract.propose_contract_owner(Some(accounts(1)));
+    assert_eq!(s.contract.get_contract_owner(), accounts(0));
+
+    set_context(accounts(1), 0, 0);
+    s.contract.accept_contract_owner();
+    assert_eq!(s.contract.get_contract_owner(), accounts(1));
+    s.contract.stage_upgrade(code_hash(b"code"));
+}
+
+#[test]
+#[should_panic(expected = "Not owner")]
+fn migrate_by_other_account_panics() {
+    let s = setup();
+    env::state_write(&s.contract);
+    set_context(accounts(1), 0, 0);
+    HackathonContract::migrate(None);
+}
+
+#[test]
+fn source_metadata_reports_the_interface_version() {
+    set_context(accounts(0), 0, 0);
+    let contract = HackathonContract::init(None, None);
+
+    let metadata = contract.contract_source_metadata();
+    assert_eq!(metadata.version, env!("CARGO_PKG_VERSION"));
+    assert!(metadata
+        .standards
+        .iter()
+        .any(|standard| standard.standard == "nep330"));
+}
+
+const BOND: Balance = ONE_NEAR / 10;
+
+fn dao_id() -> AccountId {
+    "dao.near".parse().unwrap()
+}
+
+// Contract accounts call through receipts, so the signer is whoever started the transaction
+fn set_contract_context(contract_id: AccountId, signer: AccountId, deposit: Balance) {
+    testing_env!(VMContextBuilder::new()
+        .signer_account_id(signer)
+        .predecessor_account_id(contract_id)
+        .block_timestamp(AFTER_END)
+        .attached_deposit(deposit)
+        .build());
+}
+
+// The function call sent to `receiver_id` by the last call, with its JSON args and deposit
+fn sent_call(receiver_id: &AccountId) -> (String, serde_json::Value, Balance) {
+    get_created_receipts()
+        .into_iter()
+        .filter(|receipt| receipt.receiver_id == *receiver_id)
+        .flat_map(|receipt| receipt.actions)
+        .find_map(|action| match action {
+            VmAction::FunctionCall {
+                function_name,
+                args,
+                deposit,
+                ..
+            } => Some((
+                function_name,
+                serde_json::from_slice(&args).unwrap(),
+                deposit,
+            )),
+            _ => None,
+        })
+        .expect("No call sent")
+}
+
+// alice's hackathon pays through the DAO, bob's team won its award
+fn dao_setup() -> (Setup, MockDao) {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract.set_payout_dao(s.hackathon_id, Some(dao_id()));
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+
+    let dao = MockDao::new(U128(BOND));
+    (s, dao)
+}
+
+// Raise the payout and run `add_proposal` on the mock DAO, as the receipt would
+fn propose_payout(s: &mut Setup, dao: &mut MockDao) -> u64 {
+    set_context(accounts(0), AFTER_END, BOND);
+    s.contract
+        .propose_award_payout(s.hackathon_id, s.category_id, s.award_id);
+
+    let (method_name, args, deposit) = sent_call(&dao_id());
+    assert_eq!(method_name, "add_proposal");
+    let contract_id = env::current_account_id();
+    set_contract_context(contract_id, accounts(0), deposit);
+    dao.add_proposal(serde_json::from_value(args["proposal"].clone()).unwrap())
+}
+
+#[test]
+fn dao_payout_is_proposed_and_paid_on_approval() {
+    let (mut s, mut dao) = dao_setup();
+    let proposal_id = propose_payout(&mut s, &mut dao);
+    assert_eq!(
+        s.contract.awards.get(&s.award_id).unwrap().payout,
+        Some(AwardPayout::Proposing {
+            dao_id: dao_id(),
+            receiver_id: accounts(1)
+        })
+    );
+
+    let proposal = dao.get_proposal(proposal_id);
+    assert_eq!(proposal.proposer, env::current_account_id());
+    assert_eq!(
+        proposal.kind,
+        mock_dao::ProposalKind::Transfer {
+            token_id: String::new(),
+            receiver_id: accounts(1),
+            amount: U128(ONE_NEAR),
+            msg: None,
+        }
+    );
+
+    let contract_id = env::current_account_id();
+    set_contract_context(contract_id.clone(), accounts(0), 0);
+    let recorded = s.contract.on_payout_proposed(
+        s.hackathon_id,
+        s.category_id,
+        s.award_id,
+        accounts(0),
+        U128(BOND),
+        Ok(proposal_id),
+    );
+    assert_eq!(recorded, Some(proposal_id));
+    assert_eq!(last_event()["event"], "award_payout_proposed");
+    let award = s.contract.get_award_by_id(s.award_id).unwrap();
+    assert_eq!(
+        award.payout,
+        Some(AwardPayout::Proposed {
+            dao_id: dao_id(),
+            proposal_id,
+            receiver_id: accounts(1)
+        })
+    );
+    assert!(!award.is_awarded);
+
+    // Anyone may read the result of the vote back
+    dao.act_proposal(proposal_id, Action::VoteApprove);
+    set_context(accounts(3), AFTER_END, 0);
+    s.contract
+        .check_award_payout(s.hackathon_id, s.category_id, s.award_id);
+    let (method_name, args, _) = sent_call(&dao_id());
+    assert_eq!(method_name, "get_proposal");
+    assert_eq!(args["id"], proposal_id);
+
+    let proposal = serde_json::to_value(dao.get_proposal(proposal_id)).unwrap();
+    set_contract_context(contract_id, accounts(3), 0);
+    let status = s.contract.on_payout_checked(
+        s.hackathon_id,
+        s.category_id,
+        s.award_id,
+        proposal_id,
+        Ok(serde_json::from_value(proposal).unwrap()),
+    );
+    assert_eq!(status, Some(ProposalStatus::Approved));
+    assert_eq!(last_event()["event"], "award_paid");
+    assert_eq!(last_event()["data"]["receiver_id"], accounts(1).to_string());
+
+    assert!(s.contract.awards.get(&s.award_id).unwrap().is_awarded);
+    let check = s.contract.get_prize_totals_check(s.hackathon_id).unwrap();
+    assert_eq!(check.cached[0].paid, U128(ONE_NEAR));
+    assert!(check.consistent);
+}
+
+#[test]
+fn failed_dao_proposals_can_be_raised_again() {
+    let (mut s, mut dao) = dao_setup();
+    propose_payout(&mut s, &mut dao);
+
+    // A proposal the DAO refused gives the bond back
+    let contract_id = env::current_account_id();
+    set_contract_context(contract_id.clone(), accounts(0), 0);
+    let recorded = s.contract.on_payout_proposed(
+        s.hackathon_id,
+        s.category_id,
+        s.award_id,
+        accounts(0),
+        U128(BOND),
+        Err(PromiseError::Failed),
+    );
+    assert_eq!(recorded, None);
+    assert_eq!(s.contract.awards.get(&s.award_id).unwrap().payout, None);
+    let refund = get_created_receipts()
+        .into_iter()
+        .find(|receipt| receipt.receiver_id == accounts(0))
+        .unwrap();
+    assert_eq!(refund.actions, vec![VmAction::Transfer { deposit: BOND }]);
+
+    // A rejected proposal is forgotten once its status is read
+    let proposal_id = propose_payout(&mut s, &mut dao);
+    set_contract_context(contract_id.clone(), accounts(0), 0);
+    s.contract.on_payout_proposed(
+        s.hackathon_id,
+        s.category_id,
+        s.award_id,
+        accounts(0),
+        U128(BOND),
+        Ok(proposal_id),
+    );
+    dao.act_proposal(proposal_id, Action::VoteReject);
+    let proposal = serde_json::to_value(dao.get_proposal(proposal_id)).unwrap();
+    let status = s.contract.on_payout_checked(
+        s.hackathon_id,
+        s.category_id,
+        s.award_id,
+        proposal_id,
+        Ok(serde_json::from_value(proposal).unwrap()),
+    );
+    assert_eq!(status, Some(ProposalStatus::Rejected));
+    assert_eq!(s.contract.awards.get(&s.award_id).unwrap().payout, None);
+
+    assert_eq!(propose_payout(&mut s, &mut dao), proposal_id + 1);
+}
+
+#[test]
+#[should_panic(expected = "Payout proposed to the DAO")]
+fn award_winner_during_dao_payout() {
+    let (mut s, mut dao) = dao_setup();
+    propose_payout(&mut s, &mut dao);
+
+    set_context(accounts(0), AFTER_END, ONE_NEAR);
+    s.contract
+        .award_winner(s.hackathon_id, s.category_id, s.award_id);
+}
+
+#[test]
+#[should_panic(expected = "Payout proposed to the DAO")]
+fn withdraw_submission_during_dao_payout() {
+    let (mut s, mut dao) = dao_setup();
+    propose_payout(&mut s, &mut dao);
+
+    set_context(accounts(1), AFTER_END, 0);
+    s.contract.withdraw_submission(s.hackathon_id, 0);
+}
+
+#[test]
+#[should_panic(expected = "Payout proposed to the DAO")]
+fn leave_team_during_dao_payout() {
+    let mut s = setup();
+    let submission_id = s.submit(accounts(1), vec![s.category_id]);
+    set_context(accounts(0), BUILDING, 0);
+    s.contract.set_payout_dao(s.hackathon_id, Some(dao_id()));
+    s.contract
+        .judge_winner(s.hackathon_id, s.category_id, s.award_id, submission_id);
+    set_context(accounts(0), BUILDING, BOND);
+    s.contract
+        .propose_award_payout(s.hackathon_id, s.category_id, s.award_id);
+
+    set_context(accounts(2), BUILDING, 0);
+    s.contract.leave_team(s.team_id);
+}
+
+// Run the callback of `add_proposal` with the id the DAO returned
+fn record_proposal(s: &mut Setup, proposal_id: u64) {
+    set_contract_context(env::current_account_id(), accounts(0), 0);
+    s.contract.on_payout_proposed(
+        s.hackathon_id,
+        s.category_id,
+        s.award_id,
+        accounts(0),
+        U128(BOND),
+        Ok(proposal_id),
+    );
+}
+
+// Run the callback of `get_proposal` with the proposal in `status`
+fn apply_status(s: &mut Setup, proposal_id: u64, status: ProposalStatus) -> Option<ProposalStatus> {
+    set_contract_context(env::current_account_id(), accounts(3), 0);
+    s.contract.on_payout_checked(
+        s.hackathon_id,
+        s.category_id,
+        s.award_id,
+        proposal_id,
+        Ok(Proposal { status }),
+    )
+}
+
+#[test]
+fn approved_dao_payout_is_paid_once() {
+    let (mut s, mut dao) = dao_setup();
+    let proposal_id = propose_payout(&mut s, &mut dao);
+    record_proposal(&mut s, proposal_id);
+    apply_status(&mut s, proposal_id, ProposalStatus::Approved);
+    assert_eq!(last_event()["event"], "award_paid");
+    let award = s.contract.awards.get(&s.award_id).unwrap();
+    assert!(award.is_awarded);
+    assert_eq!(award.payout, None);
+
+    // A second check sent before the first one landed finds nothing to apply
+    let status = apply_status(&mut s, proposal_id, ProposalStatus::Approved);
+    assert_eq!(status, None);
+    assert!(get_logs().is_empty());
+    let check = s.contract.get_prize_totals_check(s.hackathon_id).unwrap();
+    assert_eq!(check.cached[0].paid, U128(ONE_NEAR));
+    assert!(check.consistent);
+}
+
+#[test]
+#[should_panic(expected = "award awarded")]
+fn check_paid_dao_payout() {
+    let (mut s, mut dao) = dao_setup();
+    let proposal_id = propose_payout(&mut s, &mut dao);
+    record_proposal(&mut s, proposal_id);
+    apply_status(&mut s, proposal_id, ProposalStatus::Approved);
+
+    set_context(accounts(3), AFTER_END, 0);
+    s.contract
+        .check_award_payout(s.hackathon_id, s.category_id, s.award_id);
+}
+
+#[test]
+fn closed_dao_proposals_can_be_raised_again() {
+    let (mut s, mut dao) = dao_setup();
+    for status in [
+        ProposalStatus::Rejected,
+        ProposalStatus::Expired,
+        ProposalStatus::Failed,
+        ProposalStatus::Removed,
+        ProposalStatus::Moved,
+    ] {
+        let proposal_id = propose_payout(&mut s, &mut dao);
+        record_proposal(&mut s, proposal_id);
+        assert_eq!(
+            apply_status(&mut s, proposal_id, ProposalStatus::InProgress),
+            Some(ProposalStatus::InProgress)
+        );
+        assert!(s.contract.awards.get(&s.award_id).unwrap().payout.is_some());
+
+        assert_eq!(apply_status(&mut s, proposal_id, status), Some(status));
+        let award = s.contract.awards.get(&s.award_id).unwrap();
+        assert_eq!(award.payout, None);
+        assert!(!award.is_awarded);
+        assert_eq!(last_event()["event"], "award_payout_closed");
+        assert_eq!(last_event()["data"]["proposal_id"], proposal_id);
+        assert_eq!(last_event()["data"]["status"], json!(status));
+    }
+    let check = s.contract.get_prize_totals_check(s.hackathon_id).unwrap();
+    assert_eq!(check.cached[0].paid, U128(0));
+}
+
+#[test]
+fn failed_add_proposal_clears_the_payout() {
+    let (mut s, mut dao) = dao_setup();
+    propose_payout(&mut s, &mut dao);
+
+    set_contract_context(env::current_account_id(), accounts(0), 0);
+    s.contract.on_payout_proposed(
+        s.hackathon_id,
+        s.category_id,
+        s.award_id,
+        accounts(0),
+        U128(BOND),
+        Err(PromiseError::Failed),
+    );
+    assert_eq!(s.contract.awards.get(&s.award_id).unwrap().payout, None);
+    propose_payout(&mut s, &mut dao);
+}
+
+#[test]
+fn owner_resets_a_payout_left_proposing() {
+    let (mut s, mut dao) = dao_setup();
+    propose_payout(&mut s, &mut dao);
+
+    // The callback never ran, the award would wait for it forever
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .reset_award_payout(s.hackathon_id, s.category_id, s.award_id);
+    assert_eq!(s.contract.awards.get(&s.award_id).unwrap().payout, None);
+    propose_payout(&mut s, &mut dao);
+}
+
+#[test]
+#[should_panic(expected = "Payout not being proposed")]
+fn reset_recorded_payout() {
+    let (mut s, mut dao) = dao_setup();
+    let proposal_id = propose_payout(&mut s, &mut dao);
+    record_proposal(&mut s, proposal_id);
+
+    set_context(accounts(0), AFTER_END, 0);
+    s.contract
+        .reset_award_payout(s.hackathon_id, s.category_id, s.award_id);
+}
+
+#[test]
+#[should_panic(expected = "Not owner")]
+fn reset_payout_not_owner() {
+    let (mut s, mut dao) = dao_setup();
+    propose_payout(&mut s, &mut dao);
+
+    set_context(accounts(1), AFTER_END, 0);
+    s.contract
+        .reset_award_payout(s.hackathon_id, s.category_id, s.award_id);
+}
+
+#[test]
+fn dao_can_own_a_hackathon() {
+    set_context(accounts(0), 0, 0);
+    let mut contract = HackathonContract::init(None, None);
+
+    // The DAO acts through function call proposals executed by one of its members
+    set_contract_context(dao_id(), accounts(1), ONE_NEAR);
+    contract.storage_deposit(None, None);
+    set_contract_context(dao_id(), accounts(1), 0);
+    contract.add_member("dao".to_string(), None, None);
+    let hackathon_id = contract.add_hackathon(hackathon_payload("h")).unwrap();
+    contract.set_payout_dao(hackathon_id, Some(dao_id()));
+
+    let hackathon = contract.hackathons.get(&hackathon_id).unwrap();
+    assert_eq!(hackathon.owner, dao_id());
+    assert_eq!(hackathon.payout_dao, Some(dao_id()));
+    let edits = contract.get_hackathon_edits(hackathon_id, None, None);
+    assert_eq!(edits.items[0].editor, dao_id());
+}
+
+#[test]
+#[should_panic(expected = "Not owner")]
+fn dao_member_cannot_act_as_the_dao() {
+    set_context(accounts(0), 0, 0);
+    let mut contract = HackathonContract::init(None, None);
+    set_contract_context(dao_id(), accounts(1), ONE_NEAR);
+    contract.storage_deposit(None, None);
+    set_contract_context(dao_id(), accounts(1), 0);
+    contract.add_member("dao".to_string(), None, None);
+    let hackathon_id = contract.add_hackathon(hackathon_payload("h")).unwrap();
+
+    // The member signs, but only the DAO's own receipts carry its account
+    set_context(accounts(1), AFTER_END, 0);
+    contract.cancel_hackathon(hackathon_id);
+}
\ No newline at end of file
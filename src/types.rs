@@ -0,0 +1,24 @@
+// The request and response types of the JSON interface, for clients of the contract
+pub use crate::artifact::{Artifact, ArtifactKind};
+pub use crate::award::{
+    AwardId, AwardJson, AwardPayout, PrizeToken, PrizeTotals, PrizeTotalsCheck,
+};
+pub use crate::category::{Category, CategoryId, CategoryJson};
+pub use crate::content::{CommitAnchor, ContentRef};
+pub use crate::dao::{Proposal, ProposalStatus};
+pub use crate::edit::{Edit, EditTarget};
+pub use crate::hackathon::{
+    CalendarCursor, HackathonId, HackathonJson, HackathonPayload, HackathonPhase,
+    HackathonSettings, HackathonSort, HackathonSummary, HackathonUpdatePayload, HackathonView,
+    HackathonWithTotalPrize,
+};
+pub use crate::member::{MemberJson, MemberJsonDetail};
+pub use crate::page::{CursorPage, Page};
+pub use crate::post::{Post, PostMatch};
+pub use crate::storage::{StorageBalance, StorageBalanceBounds};
+pub use crate::submission::{
+    SubmissionId, SubmissionJson, SubmissionRevision, SubmissionUpdatePayload,
+};
+pub use crate::team::{Team, TeamAction, TeamChange, TeamId};
+pub use crate::upgrade::StagedUpgrade;
+pub use crate::version::{ContractSourceMetadata, Standard, StateVersion};
\ No newline at end of file
@@ -0,0 +1,34 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::Base58CryptoHash;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{Gas, Timestamp};
+
+// Gas kept back by `upgrade` itself, the rest goes to `migrate` on the new code
+pub const GAS_FOR_UPGRADE: Gas = Gas(20_000_000_000_000);
+// Gas of `on_upgrade_deployed`, which clears the staged upgrade after the batch succeeded
+pub const GAS_FOR_UPGRADE_CALLBACK: Gas = Gas(10_000_000_000_000);
+
+// Define the StagedUpgrade structure - code announced for deployment once the timelock ran out
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct StagedUpgrade {
+    pub code_hash: Base58CryptoHash,
+    pub staged_at: Timestamp,
+    pub deployable_at: Timestamp,
+}
+
+// Deploy the Wasm passed as raw input. It is not JSON, so this is exported by hand instead of
+// through `near_bindgen`
+#[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub extern "C" fn upgrade() {
+    use near_sdk::env;
+
+    use crate::HackathonContract;
+
+    env::setup_panic_hook();
+    let contract: HackathonContract = env::state_read().expect("Contract is not initialized");
+    let code = env::input().expect("No code given");
+    contract.deploy_upgrade(code);
+}
\ No newline at end of file
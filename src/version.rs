@@ -0,0 +1,185 @@
+use std::marker::PhantomData;
+
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::UnorderedSet;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, AccountId, IntoStorageKey};
+
+use crate::award::{AwardId, VersionedAward};
+use crate::category::{CategoryId, VersionedCategory};
+use crate::hackathon::{HackathonId, VersionedHackathon};
+use crate::member::VersionedMember;
+use crate::submission::{SubmissionId, VersionedSubmission};
+
+// Bump together with a new variant in the versioned entity enums or a change of storage keys
+pub const STATE_VERSION: u32 = 1;
+pub const DEFAULT_MIGRATION_LIMIT: u64 = 50;
+// The first version created the member and the hackathon list both with this prefix
+const LEGACY_LIST_PREFIX: &[u8] = b"m";
+
+// Implemented by the versioned enum of a stored entity
+pub trait Versioned: BorshDeserialize {
+    type Entity: BorshSerialize;
+    // Layout stored before entities carried a version tag
+    type Legacy: BorshDeserialize;
+    // Variant index of the current layout, written in front of every stored entity
+    const CURRENT: u8;
+
+    fn from_legacy(legacy: Self::Legacy) -> Self;
+    fn into_current(self) -> Self::Entity;
+}
+
+// Define the VersionedMap structure - a LookupMap whose values are stored as a versioned enum.
+// It has the same Borsh layout as a LookupMap, so existing fields keep their prefix.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct VersionedMap<K, V> {
+    prefix: Vec<u8>,
+    #[borsh_skip]
+    marker: PhantomData<(K, V)>,
+}
+
+// Implement the VersionedMap structure
+impl<K: BorshSerialize, V: Versioned> VersionedMap<K, V> {
+    pub fn new<S: IntoStorageKey>(prefix: S) -> Self {
+        VersionedMap {
+            prefix: prefix.into_storage_key(),
+            marker: PhantomData,
+        }
+    }
+
+    fn key(&self, key: &K) -> Vec<u8> {
+        let mut raw = self.prefix.clone();
+        key.serialize(&mut raw).unwrap();
+        raw
+    }
+
+    // Untagged values are read as the legacy layout
+    pub fn get(&self, key: &K) -> Option<V::Entity> {
+        let raw = env::storage_read(&self.key(key))?;
+        let versioned = V::try_from_slice(&raw)
+            .or_else(|_| V::Legacy::try_from_slice(&raw).map(V::from_legacy))
+            .unwrap_or_else(|_| env::panic_str("Cannot deserialize value with Borsh"));
+
+        Some(versioned.into_current())
+    }
+
+    pub fn get_legacy(&self, key: &K) -> Option<V::Legacy> {
+        let raw = env::storage_read(&self.key(key))?;
+
+        Some(V::Legacy::try_from_slice(&raw).expect("Value is not in the legacy layout"))
+    }
+
+    pub fn insert(&mut self, key: &K, value: &V::Entity) {
+        let mut raw = vec![V::CURRENT];
+        value.serialize(&mut raw).unwrap();
+        env::storage_write(&self.key(key), &raw);
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        env::storage_has_key(&self.key(key))
+    }
+
+    pub fn remove(&mut self, key: &K) {
+        env::storage_remove(&self.key(key));
+    }
+
+    // Rewrite a value left in the legacy layout with the current version tag
+    pub fn upgrade(&mut self, key: &K) {
+        if let Some(legacy) = self.get_legacy(key) {
+            self.insert(key, &V::from_legacy(legacy).into_current());
+        }
+    }
+}
+
+// Define the entity sets a migration walks through, in order
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub enum MigrationStage {
+    Hackathons,
+    Categories,
+    Awards,
+    MembersList,
+}
+
+// Define the MigrationCursor structure - the next entity a resumed migration converts
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct MigrationCursor {
+    pub stage: MigrationStage,
+    pub next_id: u64,
+}
+
+// Implement the MigrationCursor structure
+impl MigrationCursor {
+    pub fn next_stage(&self) -> Option<MigrationCursor> {
+        let stage = match self.stage {
+            MigrationStage::Hackathons => MigrationStage::Categories,
+            MigrationStage::Categories => MigrationStage::Awards,
+            MigrationStage::Awards => MigrationStage::MembersList,
+            MigrationStage::MembersList => return None,
+        };
+
+        Some(MigrationCursor { stage, next_id: 0 })
+    }
+}
+
+// Key of an element slot of the shared legacy list
+pub fn legacy_list_slot(index: u64) -> Vec<u8> {
+    [LEGACY_LIST_PREFIX, b"e", &index.to_le_bytes()].concat()
+}
+
+// Key of the index entry of a serialized element of the shared legacy list
+pub fn legacy_list_index(element: &[u8]) -> Vec<u8> {
+    [LEGACY_LIST_PREFIX, b"i", element].concat()
+}
+
+// Define the state version structure (response)
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct StateVersion {
+    pub version: u32,
+    pub migration: Option<MigrationCursor>,
+}
+
+// Version of the JSON interface, the `hack-near-abi` test fails when the ABI changes without a bump
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// Define the contract source metadata structure (response) - NEP-330
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct ContractSourceMetadata {
+    pub version: String,
+    pub link: Option<String>,
+    pub standards: Vec<Standard>,
+}
+
+// Define the standard structure - one NEP the contract implements
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+#[serde(crate = "near_sdk::serde")]
+pub struct Standard {
+    pub standard: String,
+    pub version: String,
+}
+
+// Define the contract layout before state versioning
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct HackathonContractV0 {
+    pub members_list: UnorderedSet<AccountId>,
+    pub hackathons_list: UnorderedSet<HackathonId>,
+
+    pub members: VersionedMap<AccountId, VersionedMember>,
+    pub awards: VersionedMap<AwardId, VersionedAward>,
+    pub hackathons: VersionedMap<HackathonId, VersionedHackathon>,
+    pub submissions: VersionedMap<SubmissionId, VersionedSubmission>,
+    pub categories: VersionedMap<CategoryId, VersionedCategory>,
+
+    pub next_submission_id: SubmissionId,
+    pub next_category_id: CategoryId,
+    pub next_award_id: AwardId,
+    pub next_hackathon_id: HackathonId,
+}
\ No newline at end of file